@@ -1,14 +1,15 @@
 use std::{
     collections::{HashMap, HashSet},
-    fs::read_to_string,
     path::{Component, Path, PathBuf},
-    sync::Mutex,
+    sync::{Arc, Mutex},
 };
 
+use encoding_rs::Encoding;
 use indexmap::{IndexMap, IndexSet};
 use lazy_static::lazy_static;
 
 use crate::{
+    content_cache::ContentCache,
     internal_config::Config,
     load_folder_structure::{get_flattened_files_structure, File, Folder},
     utils::{clone_extend_vec, remove_comments_from_code},
@@ -53,20 +54,75 @@ struct ResolveCacheKey {
     import_path: PathBuf,
 }
 
+/// Project files by path, with an index of the paths by their lowercase
+/// version to find the imports with a different casing
+#[derive(Debug, Default)]
+struct FilesCache {
+    files: HashMap<String, File>,
+    paths_by_lowercase_path: HashMap<String, Vec<String>>,
+}
+
+impl FilesCache {
+    fn get(&self, path: &str) -> Option<&File> {
+        self.files.get(path)
+    }
+
+    fn contains_key(&self, path: &str) -> bool {
+        self.files.contains_key(path)
+    }
+
+    fn values(&self) -> impl Iterator<Item = &File> {
+        self.files.values()
+    }
+
+    fn insert(&mut self, path: String, file: File) {
+        if !self.files.contains_key(&path) {
+            self.paths_by_lowercase_path
+                .entry(path.to_lowercase())
+                .or_default()
+                .push(path.clone());
+        }
+
+        self.files.insert(path, file);
+    }
+
+    fn extend(&mut self, files: impl IntoIterator<Item = (String, File)>) {
+        for (path, file) in files {
+            self.insert(path, file);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.files.clear();
+        self.paths_by_lowercase_path.clear();
+    }
+
+    /// Path of a cached file that only differs in casing from `path`
+    fn get_different_casing_path(&self, path: &str) -> Option<&String> {
+        self.paths_by_lowercase_path
+            .get(&path.to_lowercase())?
+            .iter()
+            .find(|file_path| *file_path != path)
+    }
+}
+
+const MAX_CACHED_CLEAN_CONTENTS_SIZE: usize = 64 * 1024 * 1024;
+
 lazy_static! {
-    static ref FILES_CACHE: Mutex<HashMap<String, File>> =
-        Mutex::new(HashMap::new());
+    static ref FILES_CACHE: Mutex<FilesCache> = Mutex::new(FilesCache::default());
     static ref RESOLVE_CACHE: Mutex<HashMap<ResolveCacheKey, PathBuf>> =
         Mutex::new(HashMap::new());
     static ref IMPORTS_CACHE: Mutex<HashMap<String, IndexMap<String, Vec<Import>>>> =
         Mutex::new(HashMap::new());
     static ref EXPORTS_CACHE: Mutex<HashMap<String, Vec<Export>>> =
         Mutex::new(HashMap::new());
-    static ref CLEAN_FILE_CONTENT_CACHE: Mutex<HashMap<String, String>> =
-        Mutex::new(HashMap::new());
+    static ref CLEAN_FILE_CONTENT_CACHE: Mutex<ContentCache> =
+        Mutex::new(ContentCache::new(MAX_CACHED_CLEAN_CONTENTS_SIZE));
     pub static ref ALIASES: Mutex<IndexMap<String, String>> =
         Mutex::new(IndexMap::new());
     static ref ROOT_DIR: Mutex<String> = Mutex::new(String::from("."));
+    /// Configured `encoding` of the files resolved outside the loaded structure
+    static ref FILES_ENCODING: Mutex<Option<&'static Encoding>> = Mutex::new(None);
     static ref DEBUG_READ_EDGES_COUNT: Mutex<usize> = Mutex::new(0);
     static ref FILE_EDGES_CACHE: Mutex<HashMap<String, FileEdgesCache>> =
         Mutex::new(HashMap::new());
@@ -87,6 +143,7 @@ pub fn _setup_test() {
     CLEAN_FILE_CONTENT_CACHE.lock().unwrap().clear();
     set_aliases(IndexMap::new());
     *ROOT_DIR.lock().unwrap() = String::from(".");
+    *FILES_ENCODING.lock().unwrap() = None;
     *DEBUG_READ_EDGES_COUNT.lock().unwrap() = 0;
     FILE_EDGES_CACHE.lock().unwrap().clear();
     USED_FILES.lock().unwrap().clear();
@@ -98,6 +155,10 @@ pub fn set_aliases(aliases: IndexMap<String, String>) {
     *ALIASES.lock().unwrap() = aliases;
 }
 
+pub fn set_files_encoding(encoding: Option<&'static Encoding>) {
+    *FILES_ENCODING.lock().unwrap() = encoding;
+}
+
 fn path_to_string(path: &Path) -> String {
     path.to_string_lossy().into_owned()
 }
@@ -124,7 +185,7 @@ fn file_name_to_string(path: &Path) -> Result<String, String> {
         })
 }
 
-fn load_file_from_cache(file_path: &PathBuf) -> Result<File, String> {
+fn load_file_from_cache(file_path: &Path) -> Result<File, String> {
     let file_path_string = path_to_string(file_path);
 
     if let Some(file) = FILES_CACHE.lock().unwrap().get(&file_path_string).cloned() {
@@ -232,11 +293,17 @@ fn get_resolved_path_from(
         unresolved_file_path_string.trim_start_matches('.')
     );
 
-    let file_exists = FILES_CACHE
+    let file_is_in_cache = FILES_CACHE
         .lock()
         .unwrap()
-        .contains_key(&unresolved_file_path_string)
-        || PathBuf::from(file_abs_path.clone()).is_file();
+        .contains_key(&unresolved_file_path_string);
+
+    let file_exists =
+        file_is_in_cache || PathBuf::from(file_abs_path.clone()).is_file();
+
+    if file_exists && !file_is_in_cache {
+        check_import_path_casing(&unresolved_file_path_string)?;
+    }
 
     if !file_exists {
         let file_name = file_name_to_string(&unresolved_file_path)?;
@@ -269,6 +336,8 @@ fn get_resolved_path_from(
                 let result_path = PathBuf::from(&cache_name);
 
                 if !file_is_in_cache {
+                    check_import_path_casing(&cache_name)?;
+
                     FILES_CACHE.lock().unwrap().insert(cache_name, loaded_file);
                 }
 
@@ -281,6 +350,13 @@ fn get_resolved_path_from(
             }
         }
 
+        for paths_to_try in &test_extensions {
+            check_import_path_casing(&format!(
+                "{}{}",
+                unresolved_file_path_string, paths_to_try
+            ))?;
+        }
+
         Err(format!(
             "TS: Can't resolve path: {:?}",
             unresolved_file_path
@@ -295,6 +371,24 @@ fn get_resolved_path_from(
     }
 }
 
+/// Imports with a casing different from the project file only resolve in
+/// case-insensitive file systems
+fn check_import_path_casing(path: &str) -> Result<(), String> {
+    let different_casing_path = FILES_CACHE
+        .lock()
+        .unwrap()
+        .get_different_casing_path(path)
+        .cloned();
+
+    match different_casing_path {
+        Some(file_path) => Err(format!(
+            "TS: Import path {:?} has a different casing from the file {:?}",
+            path, file_path
+        )),
+        None => Ok(()),
+    }
+}
+
 fn normalize_relative_path(path: &Path) -> PathBuf {
     let mut normalized_path = PathBuf::from(".");
 
@@ -490,18 +584,15 @@ fn get_file_edges(
     }
 }
 
-fn get_file_content(resolved_path: &str) -> Result<Option<String>, String> {
+fn get_file_content(resolved_path: &str) -> Result<Option<Arc<str>>, String> {
     let related_file = load_file_from_cache(&PathBuf::from(resolved_path))?;
 
-    Ok(related_file.content)
+    related_file.get_text_content()
 }
 
-fn get_clean_file_content(resolved_path: &str) -> Result<Option<String>, String> {
-    if let Some(content) = CLEAN_FILE_CONTENT_CACHE
-        .lock()
-        .unwrap()
-        .get(resolved_path)
-        .cloned()
+fn get_clean_file_content(resolved_path: &str) -> Result<Option<Arc<str>>, String> {
+    if let Some(content) =
+        CLEAN_FILE_CONTENT_CACHE.lock().unwrap().get(resolved_path)
     {
         return Ok(Some(content));
     }
@@ -511,7 +602,7 @@ fn get_clean_file_content(resolved_path: &str) -> Result<Option<String>, String>
         None => return Ok(None),
     };
 
-    let clean_content = remove_comments_from_code(&file_content);
+    let clean_content: Arc<str> = remove_comments_from_code(&file_content).into();
 
     CLEAN_FILE_CONTENT_CACHE
         .lock()
@@ -691,34 +782,48 @@ fn pb_to_string(import_path: PathBuf) -> String {
     path_to_string(&import_path)
 }
 
-pub fn load_file_from_path(path: &PathBuf) -> Result<File, String> {
-    let file_content = if path
+pub fn load_file_from_path(path: &Path) -> Result<File, String> {
+    let is_ts_file = path
         .extension()
         .map(|ext| ext == "ts" || ext == "tsx")
-        .unwrap_or(false)
-    {
-        match read_to_string(path) {
-            Ok(content) => Some(content),
-            Err(err) => {
-                return Err(format!(
+        .unwrap_or(false);
+
+    // the content is read on demand, only the file existence is checked here
+    let (content_path, size) = if is_ts_file {
+        let size = path
+            .metadata()
+            .map_err(|err| {
+                format!(
                     "TS: Error reading file: {}, Error: {}",
                     path.to_str().unwrap_or("invalid path"),
                     err
-                ))
-            }
-        }
+                )
+            })?
+            .len();
+
+        (Some(path.to_path_buf()), size)
     } else {
-        None
+        (None, 0)
     };
 
     let file = File {
         basename: file_stem_to_string(path)?,
         name_with_ext: file_name_to_string(path)?,
-        content: file_content,
+        content: None,
+        content_path,
+        encoding: *FILES_ENCODING.lock().unwrap(),
+        path: Some(path.to_path_buf()),
         extension: path
             .extension()
             .map(|ext| ext.to_string_lossy().into_owned()),
         relative_path: path_to_string(path),
+        is_symlink: false,
+        size,
+        // binary files are detected when their content is read
+        line_count: 0,
+        is_binary: false,
+        is_utf8: true,
+        content_hash: None,
     };
 
     Ok(file)
@@ -820,6 +925,7 @@ pub fn load_used_project_files_deps_info_from_cfg(
         .unwrap_or_default();
 
     *ROOT_DIR.lock().unwrap() = path_to_string(root_path);
+    set_files_encoding(config.encoding);
 
     let flattened_root_structure = get_flattened_files_structure(root_structure);
     let aliases = config
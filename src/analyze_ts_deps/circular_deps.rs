@@ -4,9 +4,9 @@ use colored::Colorize;
 use super::{
     extract_file_content_imports::{Import, ImportType},
     get_file_edges, get_file_imports, get_resolved_path,
-    load_used_project_files_deps_info_from_cfg, set_aliases,
+    load_used_project_files_deps_info_from_cfg,
     modules_graph::get_node_deps,
-    ROOT_DIR,
+    set_aliases, set_files_encoding, ROOT_DIR,
 };
 
 use std::path::Path;
@@ -84,6 +84,7 @@ pub fn get_detailed_file_circular_deps_result(
             .unwrap_or_default(),
     );
     *ROOT_DIR.lock().unwrap() = path_to_string(root_dir);
+    set_files_encoding(config.encoding);
 
     let resolved_path = get_resolved_path(file_path)?
         .ok_or_else(|| format!("TS: Can't resolve path: {}", file_path.display()))?;
@@ -122,8 +122,17 @@ fn alias_prefix_match_requires_a_boundary() {
             basename: "fileA".to_string(),
             name_with_ext: "fileA.ts".to_string(),
             content: Some("export const a = 1;".to_string()),
+            content_path: None,
+            encoding: None,
+            path: None,
             extension: Some("ts".to_string()),
             relative_path: "./src2/fileA.ts".to_string(),
+            is_symlink: false,
+            size: 0,
+            line_count: 0,
+            is_binary: false,
+            is_utf8: true,
+            content_hash: None,
         },
     );
 
@@ -151,8 +160,17 @@ fn create_flatten_root_structure(
                 .unwrap()
                 .to_string(),
             content: Some(file.content),
+            content_path: None,
+            encoding: None,
+            path: None,
             extension: None,
             relative_path: file.path.to_str().unwrap().to_string(),
+            is_symlink: false,
+            size: 0,
+            line_count: 0,
+            is_binary: false,
+            is_utf8: true,
+            content_hash: None,
         };
 
         flatten_root_structure
@@ -995,8 +1013,17 @@ fn direct_circular_dep_error_blames_runtime_import_not_type() {
         basename: "fileA".to_string(),
         name_with_ext: "fileA.ts".to_string(),
         content: None,
+        content_path: None,
+        encoding: None,
+        path: None,
         extension: Some("ts".to_string()),
         relative_path: "./src/fileA.ts".to_string(),
+        is_symlink: false,
+        size: 0,
+        line_count: 0,
+        is_binary: false,
+        is_utf8: true,
+        content_hash: None,
     };
 
     let result = ts_checks::check_ts_not_have_direct_circular_deps(&file_a);
@@ -1026,8 +1053,17 @@ fn get_resolved_path_consistent_with_non_default_root() {
         basename: "fileA".to_string(),
         name_with_ext: "fileA.ts".to_string(),
         content: Some("export const a = 1;".to_string()),
+        content_path: None,
+        encoding: None,
+        path: None,
         extension: Some("ts".to_string()),
         relative_path: "./src/fileA.ts".to_string(),
+        is_symlink: false,
+        size: 0,
+        line_count: 0,
+        is_binary: false,
+        is_utf8: true,
+        content_hash: None,
     };
 
     FILES_CACHE
@@ -1071,8 +1107,17 @@ fn get_resolved_path_extension_consistent_with_non_default_root() {
         basename: "fileA".to_string(),
         name_with_ext: "fileA.ts".to_string(),
         content: Some("export const a = 1;".to_string()),
+        content_path: None,
+        encoding: None,
+        path: None,
         extension: Some("ts".to_string()),
         relative_path: "./src/fileA.ts".to_string(),
+        is_symlink: false,
+        size: 0,
+        line_count: 0,
+        is_binary: false,
+        is_utf8: true,
+        content_hash: None,
     };
 
     FILES_CACHE
@@ -1109,8 +1154,17 @@ fn get_resolved_relative_path_for_sibling_import() {
         basename: "fileB".to_string(),
         name_with_ext: "fileB.ts".to_string(),
         content: Some("export const b = 1;".to_string()),
+        content_path: None,
+        encoding: None,
+        path: None,
         extension: Some("ts".to_string()),
         relative_path: "./src/feature/fileB.ts".to_string(),
+        is_symlink: false,
+        size: 0,
+        line_count: 0,
+        is_binary: false,
+        is_utf8: true,
+        content_hash: None,
     };
 
     FILES_CACHE
@@ -1140,8 +1194,17 @@ fn get_resolved_relative_path_for_parent_import_with_extension_lookup() {
         basename: "fileB".to_string(),
         name_with_ext: "fileB.ts".to_string(),
         content: Some("export const b = 1;".to_string()),
+        content_path: None,
+        encoding: None,
+        path: None,
         extension: Some("ts".to_string()),
         relative_path: "./src/shared/fileB.ts".to_string(),
+        is_symlink: false,
+        size: 0,
+        line_count: 0,
+        is_binary: false,
+        is_utf8: true,
+        content_hash: None,
     };
 
     FILES_CACHE
@@ -1159,6 +1222,90 @@ fn get_resolved_relative_path_for_parent_import_with_extension_lookup() {
     assert_eq!(resolved, PathBuf::from("./src/shared/fileB.ts"));
 }
 
+#[test]
+fn import_with_different_casing_is_not_resolved() {
+    let _guard = TEST_MUTEX.lock().unwrap();
+
+    _setup_test();
+
+    *ROOT_DIR.lock().unwrap() = "/project".to_string();
+
+    let file = File {
+        basename: "Button".to_string(),
+        name_with_ext: "Button.tsx".to_string(),
+        content: Some("export const Button = 1;".to_string()),
+        content_path: None,
+        encoding: None,
+        path: None,
+        extension: Some("tsx".to_string()),
+        relative_path: "./src/Button.tsx".to_string(),
+        is_symlink: false,
+        size: 0,
+        line_count: 0,
+        is_binary: false,
+        is_utf8: true,
+        content_hash: None,
+    };
+
+    FILES_CACHE
+        .lock()
+        .unwrap()
+        .insert("./src/Button.tsx".to_string(), file);
+
+    let error = get_resolved_path_from(
+        Some(Path::new("./src/index.ts")),
+        Path::new("./button"),
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        error,
+        "TS: Import path \"./src/button.tsx\" has a different casing from the file \"./src/Button.tsx\""
+    );
+
+    let resolved = get_resolved_path_from(
+        Some(Path::new("./src/index.ts")),
+        Path::new("./Button"),
+    )
+    .unwrap()
+    .unwrap();
+
+    assert_eq!(resolved, PathBuf::from("./src/Button.tsx"));
+}
+
+#[test]
+fn files_cache_indexes_paths_by_lowercase_path() {
+    let mut files_cache = FilesCache::default();
+
+    files_cache.insert("./src/Button.tsx".to_string(), File::default());
+    files_cache.insert("./src/Button.tsx".to_string(), File::default());
+    files_cache.extend([("./src/index.ts".to_string(), File::default())]);
+
+    assert_eq!(
+        files_cache.get_different_casing_path("./src/button.tsx"),
+        Some(&"./src/Button.tsx".to_string())
+    );
+    assert_eq!(
+        files_cache.get_different_casing_path("./src/Button.tsx"),
+        None
+    );
+    assert_eq!(
+        files_cache.get_different_casing_path("./src/Index.ts"),
+        Some(&"./src/index.ts".to_string())
+    );
+    assert_eq!(
+        files_cache.paths_by_lowercase_path.get("./src/button.tsx"),
+        Some(&vec!["./src/Button.tsx".to_string()])
+    );
+
+    files_cache.clear();
+
+    assert_eq!(
+        files_cache.get_different_casing_path("./src/button.tsx"),
+        None
+    );
+}
+
 #[test]
 fn relative_path_resolution_cache_is_scoped_by_importer() {
     let _guard = TEST_MUTEX.lock().unwrap();
@@ -1174,8 +1321,17 @@ fn relative_path_resolution_cache_is_scoped_by_importer() {
                 basename: "shared".to_string(),
                 name_with_ext: "shared.ts".to_string(),
                 content: Some("export const value = 1;".to_string()),
+                content_path: None,
+                encoding: None,
+                path: None,
                 extension: Some("ts".to_string()),
                 relative_path: relative_path.to_string(),
+                is_symlink: false,
+                size: 0,
+                line_count: 0,
+                is_binary: false,
+                is_utf8: true,
+                content_hash: None,
             },
         );
     }
@@ -1260,3 +1416,31 @@ fn project_with_relative_and_alias_imports() {
         ]
     );
 }
+
+#[test]
+fn files_loaded_from_path_use_the_configured_encoding() {
+    let _guard = TEST_MUTEX.lock().unwrap();
+
+    _setup_test();
+
+    let root = std::env::temp_dir().join("palinter_ts_files_encoding");
+
+    let _ = std::fs::remove_dir_all(&root);
+    std::fs::create_dir_all(&root).unwrap();
+
+    let path = root.join("latin1.ts");
+    std::fs::write(&path, b"export const caf\xe9 = 1;\n").unwrap();
+
+    set_files_encoding(Some(encoding_rs::WINDOWS_1252));
+
+    let file = load_file_from_path(&path).unwrap();
+
+    assert_eq!(file.path, Some(path.clone()));
+    assert_eq!(
+        file.get_text_content().unwrap().as_deref(),
+        Some("export const café = 1;\n")
+    );
+
+    _setup_test();
+    std::fs::remove_dir_all(&root).unwrap();
+}
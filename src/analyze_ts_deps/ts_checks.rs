@@ -121,14 +121,14 @@ pub fn check_ts_not_have_unused_exports(file: &File) -> Result<(), String> {
             }
         }
 
-        let count_of_ignore_next_line_comments = if let Some(content) = &file.content
-        {
-            content
-                .matches("// palinter-ignore-unused-next-line")
-                .count()
-        } else {
-            0
-        };
+        let count_of_ignore_next_line_comments =
+            if let Some(content) = file.get_content()? {
+                content
+                    .matches("// palinter-ignore-unused-next-line")
+                    .count()
+            } else {
+                0
+            };
 
         if count_of_ignore_next_line_comments > all_ignored_exports_count {
             return Err("Unused ignore comment '// palinter-ignore-unused-next-line', remove it".to_string());
@@ -188,7 +188,7 @@ fn find_ignore_comment_lines(file: &File, ignore_suffix: &str) -> Vec<usize> {
     let line_comment = format!("// {}", ignore_comment);
     let block_comment = format!("/* {}", ignore_comment);
 
-    if let Some(content) = &file.content {
+    if let Ok(Some(content)) = file.get_content() {
         content
             .lines()
             .enumerate()
@@ -214,7 +214,7 @@ fn import_has_ignore_comment_above(
 
     let ignore_comment = format!("palinter-ignore-{}", ignore_suffix);
 
-    if let Some(content) = &file.content {
+    if let Ok(Some(content)) = file.get_content() {
         let lines: Vec<&str> = content.lines().collect();
         // import_line is 1-indexed, convert to 0-indexed
         // and go one line above
@@ -1,4 +1,5 @@
 use colored::Colorize;
+use indexmap::IndexMap;
 use rayon::prelude::*;
 use std::collections::{BTreeMap, HashMap, HashSet};
 
@@ -11,23 +12,229 @@ use crate::{
     },
     analyze_ts_deps::warm_file_deps_results_for_paths,
     internal_config::{
-        AnyNoneOr, AnyOr, Config, ErrorMsgVars, FileConditions, FileExpect,
-        FileRule, FolderConditions, FolderConfig, FolderExpect, FolderRule,
-        OneOfBlocks,
+        Acronyms, AnyNoneOr, AnyOr, Config, DotfilesPolicy, DuplicateContent,
+        ErrorMsgVars, FileConditions, FileExpect, FileRule, FolderConditions,
+        FolderConfig, FolderExpect, FolderRule, OneOfBlocks, Severity,
+        SymlinksPolicy,
     },
     load_folder_structure::{File, Folder, FolderChild},
     utils::clone_extend_vec,
 };
+use unicode_normalization::UnicodeNormalization;
+
+use self::data_checks::{
+    check_data_matches, check_json_schema, clear_json_schema_validators, DataFormat,
+};
+
+use self::markdown_checks::{
+    check_front_matter_has, check_has_heading, check_relative_links_resolve,
+};
 
 use self::checks::{
-    check_content, check_content_not_matches, check_file_is_not_empty,
-    check_folder_min_children, check_negated_path_pattern,
-    check_negated_root_files_has_pattern, check_path_pattern,
-    check_root_files_find_pattern, check_root_files_has_pattern,
-    expand_to_capture_case_variation, extension_is, has_sibling_file, name_case_is,
-    path_pattern_match, Capture,
+    check_content, check_content_is_unique, check_content_not_matches,
+    check_file_encoding, check_file_is_not_empty, check_file_max_lines,
+    check_file_max_size, check_file_min_lines, check_folder_is_not_empty,
+    check_folder_max_children, check_folder_max_depth, check_folder_min_children,
+    check_negated_path_pattern, check_negated_root_files_has_pattern,
+    check_path_pattern, check_root_files_find_pattern, check_root_files_has_pattern,
+    expand_to_capture_case_variation, extension_is, get_duplicated_content_groups,
+    has_companion_file, has_sibling, in_folder_pattern_match, is_empty_folder,
+    is_in_scope, name_case_is, name_is_unique_in, not_has_sibling,
+    path_pattern_match, root_path_pattern_match, set_project_paths, suffix_is,
+    Capture, SiblingKind,
 };
 
+/// Options of the whole check, shared by all the checked folders
+struct CheckOptions<'a> {
+    config: &'a Config,
+    allow_warnings: bool,
+}
+
+/// The `--severity` overrides take precedence over the expect severity, which
+/// takes precedence over the rule severity
+fn get_reported_severity(
+    rule_id: &Option<String>,
+    rule_severity: Severity,
+    expect_severity: Option<Severity>,
+    options: &CheckOptions,
+) -> Severity {
+    let severity = rule_id
+        .as_ref()
+        .and_then(|rule_id| options.config.severity_overrides.get(rule_id).copied())
+        .or(expect_severity)
+        .unwrap_or(rule_severity);
+
+    if severity == Severity::Warn && !options.allow_warnings {
+        Severity::Error
+    } else {
+        severity
+    }
+}
+
+/// The expects of the `one_of` rules can't have a severity, their problems
+/// only mean that the rule doesn't match
+fn get_one_of_severity(_: Option<Severity>) -> Severity {
+    Severity::Error
+}
+
+fn get_reported_symlink_error(
+    is_symlink: bool,
+    symlinks: SymlinksPolicy,
+    name: String,
+    folder_path: &str,
+    append_error: &str,
+) -> Option<String> {
+    if !is_symlink || symlinks != SymlinksPolicy::Report {
+        return None;
+    }
+
+    Some(format!(
+        "Symlink {} is not allowed in folder {}{}",
+        name.bright_yellow(),
+        folder_path.bright_red(),
+        append_error
+    ))
+}
+
+/// Reports the children names that are equal after case folding and unicode
+/// normalization, they can't coexist in case-insensitive file systems
+fn get_case_collisions_errors(
+    folder: &Folder,
+    folder_config: Option<&FolderConfig>,
+    folder_path: &str,
+    inherited_case_collisions: bool,
+) -> Vec<String> {
+    let case_collisions = folder_config
+        .and_then(|folder_config| folder_config.case_collisions)
+        .unwrap_or(inherited_case_collisions);
+
+    let mut errors = vec![];
+
+    if case_collisions {
+        let mut names_by_key: IndexMap<String, Vec<String>> = IndexMap::new();
+
+        for child in &folder.children {
+            let (name, display_name) = match child {
+                FolderChild::FileChild(file) => {
+                    (&file.name_with_ext, file.name_with_ext.clone())
+                }
+                FolderChild::Folder(sub_folder) => {
+                    (&sub_folder.name, format!("/{}", sub_folder.name))
+                }
+            };
+
+            names_by_key
+                .entry(name.nfc().collect::<String>().to_lowercase())
+                .or_default()
+                .push(display_name);
+        }
+
+        for names in names_by_key.values().filter(|names| names.len() > 1) {
+            errors.push(format!(
+                "Names {} collide in folder {}, they only differ in case or unicode normalization",
+                names
+                    .iter()
+                    .map(|name| name.bright_yellow().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                folder_path.bright_red()
+            ));
+        }
+    }
+
+    for child in &folder.children {
+        if let FolderChild::Folder(sub_folder) = child {
+            errors.extend(get_case_collisions_errors(
+                sub_folder,
+                folder_config.and_then(|folder_config| {
+                    folder_config
+                        .sub_folders_config
+                        .get(&to_folder_config_name(&sub_folder.name))
+                }),
+                &format!("{}/{}", folder_path, sub_folder.name),
+                case_collisions,
+            ));
+        }
+    }
+
+    errors
+}
+
+/// Reports the outermost empty folders, the folders inside them are not
+/// reported again
+fn get_empty_folders_errors(
+    folder: &Folder,
+    folder_path: &str,
+    placeholders: &[String],
+) -> Vec<String> {
+    let mut errors = vec![];
+
+    for child in &folder.children {
+        if let FolderChild::Folder(sub_folder) = child {
+            let sub_folder_path = format!("{}/{}", folder_path, sub_folder.name);
+
+            if is_empty_folder(sub_folder, placeholders) {
+                errors.push(format!(
+                    "Folder {}\n • folder is empty",
+                    format!("{}:", sub_folder_path).bright_red()
+                ));
+            } else {
+                errors.extend(get_empty_folders_errors(
+                    sub_folder,
+                    &sub_folder_path,
+                    placeholders,
+                ));
+            }
+        }
+    }
+
+    errors
+}
+
+/// Reports each group of files with the same content once, only the files
+/// inside the configured scopes are considered
+fn get_duplicate_content_errors(
+    duplicate_content: &DuplicateContent,
+) -> Vec<String> {
+    let mut errors = vec![];
+    let mut reported_groups: HashSet<Vec<String>> = HashSet::new();
+
+    for group in get_duplicated_content_groups() {
+        for scope in &duplicate_content.scopes {
+            let paths_in_scope: Vec<String> = group
+                .iter()
+                .filter(|path| is_in_scope(path, scope))
+                .cloned()
+                .collect();
+
+            if paths_in_scope.len() < 2 || reported_groups.contains(&paths_in_scope)
+            {
+                continue;
+            }
+
+            errors.push(format!(
+                "Files {} have the same content",
+                paths_in_scope.join(", ").bright_red()
+            ));
+
+            reported_groups.insert(paths_in_scope);
+        }
+    }
+
+    errors
+}
+
+/// With the `only_when_configured` policy, dotfiles and dot folders that are
+/// not matched by any rule or folder config are ignored
+fn is_unconfigured_dotfile_ignored(name: &str, dotfiles: DotfilesPolicy) -> bool {
+    name.starts_with('.') && dotfiles == DotfilesPolicy::OnlyWhenConfigured
+}
+
+fn get_parent_path(path: &str) -> &str {
+    path.rsplit_once('/')
+        .map_or("", |(parent_path, _)| parent_path)
+}
+
 #[derive(Debug, Default)]
 pub struct ConditionsResult {
     pub captures: Vec<Capture>,
@@ -36,72 +243,155 @@ pub struct ConditionsResult {
 fn file_matches_condition(
     file: &File,
     conditions: &AnyOr<FileConditions>,
+    acronyms: &Acronyms,
 ) -> Option<ConditionsResult> {
     match conditions {
         AnyOr::Any => Some(ConditionsResult::default()),
-        AnyOr::Or(conditions) => {
-            let mut has_name_captures: Vec<Capture> = Vec::new();
+        AnyOr::Or(conditions) => file_matches_conditions(file, conditions, acronyms),
+    }
+}
 
-            if let Some(extensions) = &conditions.has_extension {
-                if !extensions.contains(&file.extension.clone().unwrap_or_default())
-                {
-                    return None;
-                }
-            }
+fn file_matches_conditions(
+    file: &File,
+    conditions: &FileConditions,
+    acronyms: &Acronyms,
+) -> Option<ConditionsResult> {
+    let mut has_name_captures: Vec<Capture> = Vec::new();
 
-            if let Some(pattern) = &conditions.has_name {
-                if let Ok(captures) =
-                    path_pattern_match(&file.name_with_ext, pattern)
-                {
-                    has_name_captures.extend(captures)
-                } else {
-                    return None;
-                }
-            }
+    if let Some(extensions) = &conditions.has_extension {
+        if !extensions.contains(&file.extension.clone().unwrap_or_default()) {
+            return None;
+        }
+    }
 
-            if let Some(pattern) = &conditions.not_has_name {
-                if path_pattern_match(&file.name_with_ext, pattern).is_ok() {
-                    return None;
-                }
-            }
+    if conditions.is_symlink && !file.is_symlink {
+        return None;
+    }
 
-            if let Some(content_matches) = &conditions.has_content {
-                if check_content(&file.content, content_matches, &[], false).is_err()
-                {
-                    return None;
-                }
-            }
+    if let Some(size) = conditions.is_larger_than {
+        if file.size <= size {
+            return None;
+        }
+    }
 
-            if let Some(content_matches) = &conditions.has_any_content {
-                if check_content(
-                    &file.content,
-                    content_matches,
-                    &has_name_captures,
-                    true,
-                )
-                .is_err()
-                {
-                    return None;
-                }
-            }
+    if conditions.is_binary && !file.is_binary {
+        return None;
+    }
 
-            if let Some(content_matches) = &conditions.not_has_content {
-                if check_content_not_matches(
-                    &file.content,
-                    content_matches,
-                    &has_name_captures,
-                )
-                .is_err()
-                {
-                    return None;
-                }
-            }
+    if let Some(suffixes) = &conditions.has_suffix {
+        if !file
+            .suffixes()
+            .iter()
+            .any(|suffix| suffixes.iter().any(|expected| expected == suffix))
+        {
+            return None;
+        }
+    }
 
-            Some(ConditionsResult {
-                captures: has_name_captures,
-            })
+    if let Some(pattern) = &conditions.has_name {
+        if let Ok(captures) = path_pattern_match(&file.name_with_ext, pattern) {
+            has_name_captures.extend(captures)
+        } else {
+            return None;
+        }
+    }
+
+    if let Some(pattern) = &conditions.not_has_name {
+        if path_pattern_match(&file.name_with_ext, pattern).is_ok() {
+            return None;
+        }
+    }
+
+    if let Some(pattern) = &conditions.has_path {
+        if let Ok(captures) = root_path_pattern_match(&file.relative_path, pattern) {
+            has_name_captures.extend(captures)
+        } else {
+            return None;
+        }
+    }
+
+    if let Some(pattern) = &conditions.not_has_path {
+        if root_path_pattern_match(&file.relative_path, pattern).is_ok() {
+            return None;
+        }
+    }
+
+    if let Some(pattern) = &conditions.in_folder {
+        if let Ok(captures) =
+            in_folder_pattern_match(get_parent_path(&file.relative_path), pattern)
+        {
+            has_name_captures.extend(captures)
+        } else {
+            return None;
+        }
+    }
+
+    if let Some(pattern) = &conditions.not_in_folder {
+        if in_folder_pattern_match(get_parent_path(&file.relative_path), pattern)
+            .is_ok()
+        {
+            return None;
         }
     }
+
+    if let Some(content_matches) = &conditions.has_content {
+        if let Ok(captures) =
+            check_content(file, content_matches, &[], false, acronyms)
+        {
+            has_name_captures.extend(captures)
+        } else {
+            return None;
+        }
+    }
+
+    if let Some(content_matches) = &conditions.has_any_content {
+        if let Ok(captures) =
+            check_content(file, content_matches, &has_name_captures, true, acronyms)
+        {
+            has_name_captures.extend(captures)
+        } else {
+            return None;
+        }
+    }
+
+    if let Some(content_matches) = &conditions.not_has_content {
+        if check_content_not_matches(
+            file,
+            content_matches,
+            &has_name_captures,
+            acronyms,
+        )
+        .is_err()
+        {
+            return None;
+        }
+    }
+
+    if let Some(all_of) = &conditions.all_of {
+        for sub_conditions in all_of {
+            has_name_captures.extend(
+                file_matches_conditions(file, sub_conditions, acronyms)?.captures,
+            );
+        }
+    }
+
+    if let Some(any_of) = &conditions.any_of {
+        let matched = any_of.iter().find_map(|sub_conditions| {
+            file_matches_conditions(file, sub_conditions, acronyms)
+        })?;
+
+        has_name_captures.extend(matched.captures);
+    }
+
+    if let Some(not) = &conditions.not {
+        if file_matches_conditions(file, not, acronyms).is_some() {
+            return None;
+        }
+    }
+
+    Some(ConditionsResult {
+        captures: has_name_captures,
+    })
 }
 
 fn append_expect_error(
@@ -141,19 +431,6 @@ fn replace_error_msg_vars(
     }
 }
 
-fn append_error_to_vec(
-    result: Result<(), String>,
-    expect_error_msg: &Option<String>,
-    error_msg_vars: &ErrorMsgVars,
-) -> Result<(), Vec<String>> {
-    let result = append_expect_error(result, expect_error_msg, error_msg_vars);
-
-    match result {
-        Ok(_) => Ok(()),
-        Err(error) => Err(vec![error]),
-    }
-}
-
 fn check_file_expect(
     file: &File,
     expected: &AnyNoneOr<Vec<FileExpect>>,
@@ -161,251 +438,697 @@ fn check_file_expect(
     conditions_result: &ConditionsResult,
     context_conditions: &[Capture],
     error_msg_vars: &ErrorMsgVars,
-) -> Result<(), Vec<String>> {
+    get_severity: &dyn Fn(Option<Severity>) -> Severity,
+    acronyms: &Acronyms,
+) -> Result<(), Problems> {
+    let mut problems = Problems::default();
+
     if let AnyNoneOr::None = expected {
-        return Err(vec!["File is not expected".to_string()]);
+        problems.push(get_severity(None), "File is not expected".to_string());
     }
 
-    let mut errors = Vec::new();
+    if let AnyNoneOr::Or(expected) = expected {
+        let captures =
+            clone_extend_vec(&conditions_result.captures, context_conditions);
+
+        for expect in expected {
+            let severity = get_severity(expect.severity);
+
+            if severity == Severity::Off {
+                continue;
+            }
+
+            for error in get_file_expect_result(
+                file,
+                expect,
+                folder,
+                &captures,
+                error_msg_vars,
+                acronyms,
+            )
+            .errors
+            {
+                problems.push(severity, error);
+            }
+        }
+    }
+
+    problems.into_result()
+}
+
+fn get_file_expect_result(
+    file: &File,
+    expect: &FileExpect,
+    folder: &Folder,
+    captures: &[Capture],
+    error_msg_vars: &ErrorMsgVars,
+    acronyms: &Acronyms,
+) -> ExpectResult {
+    let mut expect_result = ExpectResult::default();
 
     let mut check_result =
         |result: Result<(), String>, expect_error_msg: &Option<String>| {
+            expect_result.checks += 1;
+
             if let Err(error) =
                 append_expect_error(result, expect_error_msg, error_msg_vars)
             {
-                errors.push(error);
+                expect_result.errors.push(error);
             }
         };
 
-    if let AnyNoneOr::Or(expected) = expected {
-        let captures =
-            clone_extend_vec(&conditions_result.captures, context_conditions);
+    let mut pass_some_expect = false;
 
-        for expect in expected {
-            let mut pass_some_expect = false;
+    if let Some(file_name_case_is) = &expect.name_case_is {
+        pass_some_expect = true;
+        check_result(
+            name_case_is(file.name(), file_name_case_is, acronyms),
+            &expect.error_msg,
+        );
+    }
 
-            if let Some(file_name_case_is) = &expect.name_case_is {
-                pass_some_expect = true;
-                check_result(
-                    name_case_is(&file.basename, file_name_case_is),
-                    &expect.error_msg,
-                );
-            }
+    if let Some(file_extension_is) = &expect.extension_is {
+        pass_some_expect = true;
+        check_result(
+            extension_is(&file.extension, file_extension_is),
+            &expect.error_msg,
+        );
+    }
 
-            if let Some(file_extension_is) = &expect.extension_is {
-                pass_some_expect = true;
-                check_result(
-                    extension_is(&file.extension, file_extension_is),
-                    &expect.error_msg,
-                );
-            }
+    if let Some(file_suffix_is) = &expect.suffix_is {
+        pass_some_expect = true;
+        check_result(
+            suffix_is(&file.suffixes(), file_suffix_is),
+            &expect.error_msg,
+        );
+    }
 
-            if let Some(sibling_file_pattern) = &expect.have_sibling_file {
-                pass_some_expect = true;
-                check_result(
-                    has_sibling_file(sibling_file_pattern, folder, &captures),
-                    &expect.error_msg,
-                );
-            }
+    if let Some(sibling_file) = &expect.have_sibling_file {
+        pass_some_expect = true;
+        check_result(
+            has_sibling(
+                file,
+                folder,
+                SiblingKind::File,
+                sibling_file,
+                captures,
+                acronyms,
+            ),
+            &expect.error_msg,
+        );
+    }
 
-            if let Some(content_matches) = &expect.content_matches {
-                pass_some_expect = true;
-                check_result(
-                    check_content(&file.content, content_matches, &captures, false),
-                    &expect.error_msg,
-                );
-            }
+    if let Some(sibling_folder) = &expect.have_sibling_folder {
+        pass_some_expect = true;
+        check_result(
+            has_sibling(
+                file,
+                folder,
+                SiblingKind::Folder,
+                sibling_folder,
+                captures,
+                acronyms,
+            ),
+            &expect.error_msg,
+        );
+    }
 
-            if let Some(content_matches_some) = &expect.content_matches_some {
-                pass_some_expect = true;
-                check_result(
-                    check_content(
-                        &file.content,
-                        content_matches_some,
-                        &captures,
-                        true,
-                    ),
-                    &expect.error_msg,
-                );
-            }
+    if let Some(companion_file) = &expect.have_companion_file {
+        pass_some_expect = true;
+        check_result(
+            has_companion_file(file, companion_file, captures, acronyms),
+            &expect.error_msg,
+        );
+    }
 
-            if let Some(name_is) = &expect.name_is {
-                pass_some_expect = true;
-                check_result(
-                    check_path_pattern(&file.name_with_ext, name_is, &captures),
-                    &expect.error_msg,
-                );
-            }
+    if let Some(sibling_file_pattern) = &expect.not_have_sibling_file {
+        pass_some_expect = true;
+        check_result(
+            not_has_sibling(
+                file,
+                folder,
+                SiblingKind::File,
+                sibling_file_pattern,
+                captures,
+                acronyms,
+            ),
+            &expect.error_msg,
+        );
+    }
 
-            if let Some(name_is_not) = &expect.name_is_not {
-                pass_some_expect = true;
-                check_result(
-                    check_negated_path_pattern(
-                        &file.name_with_ext,
-                        name_is_not,
-                        &captures,
-                    ),
-                    &expect.error_msg,
-                );
-            }
+    if let Some(sibling_folder_pattern) = &expect.not_have_sibling_folder {
+        pass_some_expect = true;
+        check_result(
+            not_has_sibling(
+                file,
+                folder,
+                SiblingKind::Folder,
+                sibling_folder_pattern,
+                captures,
+                acronyms,
+            ),
+            &expect.error_msg,
+        );
+    }
 
-            if let Some(content_not_matches) = &expect.content_not_matches {
-                pass_some_expect = true;
-                check_result(
-                    check_content_not_matches(
-                        &file.content,
-                        content_not_matches,
-                        &captures,
-                    ),
+    if let Some(content_matches) = &expect.content_matches {
+        pass_some_expect = true;
+        check_result(
+            check_content(file, content_matches, captures, false, acronyms)
+                .map(|_| ()),
+            &expect.error_msg,
+        );
+    }
+
+    if let Some(content_matches_some) = &expect.content_matches_some {
+        pass_some_expect = true;
+        check_result(
+            check_content(file, content_matches_some, captures, true, acronyms)
+                .map(|_| ()),
+            &expect.error_msg,
+        );
+    }
+
+    if let Some(name_is) = &expect.name_is {
+        pass_some_expect = true;
+        check_result(
+            check_path_pattern(&file.name_with_ext, name_is, captures, acronyms),
+            &expect.error_msg,
+        );
+    }
+
+    if let Some(name_is_not) = &expect.name_is_not {
+        pass_some_expect = true;
+        check_result(
+            check_negated_path_pattern(
+                &file.name_with_ext,
+                name_is_not,
+                captures,
+                acronyms,
+            ),
+            &expect.error_msg,
+        );
+    }
+
+    if let Some(unique_name) = &expect.name_is_unique_in {
+        pass_some_expect = true;
+        check_result(
+            name_is_unique_in(
+                file.name(),
+                &file.relative_path,
+                SiblingKind::File,
+                unique_name,
+            ),
+            &expect.error_msg,
+        );
+    }
+
+    if let Some(content_not_matches) = &expect.content_not_matches {
+        pass_some_expect = true;
+        check_result(
+            check_content_not_matches(file, content_not_matches, captures, acronyms),
+            &expect.error_msg,
+        );
+    }
+
+    if expect.content_is_unique {
+        pass_some_expect = true;
+        check_result(check_content_is_unique(file), &expect.error_msg);
+    }
+
+    if let Some(json_matches) = &expect.json_matches {
+        pass_some_expect = true;
+        check_result(
+            check_data_matches(
+                file,
+                json_matches,
+                DataFormat::Json,
+                captures,
+                acronyms,
+            ),
+            &expect.error_msg,
+        );
+    }
+
+    if let Some(yaml_matches) = &expect.yaml_matches {
+        pass_some_expect = true;
+        check_result(
+            check_data_matches(
+                file,
+                yaml_matches,
+                DataFormat::Yaml,
+                captures,
+                acronyms,
+            ),
+            &expect.error_msg,
+        );
+    }
+
+    if let Some(json_schema) = &expect.json_schema {
+        pass_some_expect = true;
+        check_result(check_json_schema(file, json_schema), &expect.error_msg);
+    }
+
+    if let Some(keys) = &expect.front_matter_has {
+        pass_some_expect = true;
+        check_result(check_front_matter_has(file, keys), &expect.error_msg);
+    }
+
+    if let Some(heading_patterns) = &expect.has_heading {
+        pass_some_expect = true;
+        check_result(
+            check_has_heading(file, heading_patterns, captures, acronyms),
+            &expect.error_msg,
+        );
+    }
+
+    if expect.relative_links_resolve {
+        pass_some_expect = true;
+        check_result(check_relative_links_resolve(file), &expect.error_msg);
+    }
+
+    if expect.is_not_empty {
+        pass_some_expect = true;
+        check_result(check_file_is_not_empty(file), &expect.error_msg);
+    }
+
+    if let Some(max_size) = expect.max_size {
+        pass_some_expect = true;
+        check_result(check_file_max_size(file, max_size), &expect.error_msg);
+    }
+
+    if let Some(max_lines) = expect.max_lines {
+        pass_some_expect = true;
+        check_result(check_file_max_lines(file, max_lines), &expect.error_msg);
+    }
+
+    if let Some(min_lines) = expect.min_lines {
+        pass_some_expect = true;
+        check_result(check_file_min_lines(file, min_lines), &expect.error_msg);
+    }
+
+    if let Some(encoding) = &expect.encoding_is {
+        pass_some_expect = true;
+        check_result(check_file_encoding(file, encoding), &expect.error_msg);
+    }
+
+    if let Some(ts_expect) = &expect.ts {
+        if ts_expect.not_have_unused_exports {
+            pass_some_expect = true;
+            check_result(check_ts_not_have_unused_exports(file), &expect.error_msg);
+        }
+
+        if ts_expect.not_have_circular_deps {
+            pass_some_expect = true;
+            check_result(check_ts_not_have_circular_deps(file), &expect.error_msg);
+        }
+
+        if let Some(disallow) = &ts_expect.not_have_deps_from {
+            pass_some_expect = true;
+            check_result(
+                check_ts_not_have_deps_from(file, disallow),
+                &expect.error_msg,
+            );
+        }
+
+        if let Some(allowed) = &ts_expect.not_have_deps_outside {
+            pass_some_expect = true;
+            check_result(
+                check_ts_not_have_deps_outside(file, allowed),
+                &expect.error_msg,
+            );
+        }
+
+        if let Some(allowed) = &ts_expect.not_have_exports_used_outside {
+            pass_some_expect = true;
+            check_result(
+                check_ts_not_have_used_exports_outside(file, allowed),
+                &expect.error_msg,
+            );
+        }
+
+        if ts_expect.not_have_direct_circular_deps {
+            pass_some_expect = true;
+            check_result(
+                check_ts_not_have_direct_circular_deps(file),
+                &expect.error_msg,
+            );
+        }
+
+        if let Some(imports) = &ts_expect.have_imports {
+            pass_some_expect = true;
+            check_result(check_ts_have_imports(file, imports), &expect.error_msg);
+        }
+
+        if let Some(not_have_imports) = &ts_expect.not_have_imports {
+            pass_some_expect = true;
+            check_result(
+                check_ts_not_have_imports(file, not_have_imports),
+                &expect.error_msg,
+            );
+        }
+    }
+
+    if let Some(any_of) = &expect.any_of {
+        pass_some_expect = true;
+        check_result(
+            check_any_of_expects(any_of, |sub_expect| {
+                get_file_expect_result(
+                    file,
+                    sub_expect,
+                    folder,
+                    captures,
+                    error_msg_vars,
+                    acronyms,
+                )
+            }),
+            &expect.error_msg,
+        );
+    }
+
+    if let Some(not) = &expect.not {
+        pass_some_expect = true;
+        check_result(
+            check_not_expect(&get_file_expect_result(
+                file,
+                not,
+                folder,
+                captures,
+                error_msg_vars,
+                acronyms,
+            )),
+            &expect.error_msg,
+        );
+    }
+
+    if let Some(all_of) = &expect.all_of {
+        pass_some_expect = true;
+
+        for sub_expect in all_of {
+            let sub_result = get_file_expect_result(
+                file,
+                sub_expect,
+                folder,
+                captures,
+                error_msg_vars,
+                acronyms,
+            );
+
+            expect_result.checks += sub_result.checks;
+
+            for error in sub_result.errors {
+                if let Err(error) = append_expect_error(
+                    Err(error),
                     &expect.error_msg,
-                );
+                    error_msg_vars,
+                ) {
+                    expect_result.errors.push(error);
+                }
             }
+        }
+    }
 
-            if expect.is_not_empty {
-                pass_some_expect = true;
-                check_result(check_file_is_not_empty(file), &expect.error_msg);
-            }
+    if cfg!(debug_assertions) && !pass_some_expect {
+        panic!("Unexpected expect {:#?}", expect);
+    }
 
-            if let Some(ts_expect) = &expect.ts {
-                if ts_expect.not_have_unused_exports {
-                    pass_some_expect = true;
-                    check_result(
-                        check_ts_not_have_unused_exports(file),
-                        &expect.error_msg,
-                    );
-                }
+    expect_result
+}
 
-                if ts_expect.not_have_circular_deps {
-                    pass_some_expect = true;
-                    check_result(
-                        check_ts_not_have_circular_deps(file),
-                        &expect.error_msg,
-                    );
-                }
+#[derive(Debug, Default)]
+struct ExpectResult {
+    errors: Vec<String>,
+    checks: usize,
+}
 
-                if let Some(disallow) = &ts_expect.not_have_deps_from {
-                    pass_some_expect = true;
-                    check_result(
-                        check_ts_not_have_deps_from(file, disallow),
-                        &expect.error_msg,
-                    );
-                }
+impl ExpectResult {
+    fn passed_checks(&self) -> usize {
+        self.checks.saturating_sub(self.errors.len())
+    }
+}
 
-                if let Some(allowed) = &ts_expect.not_have_deps_outside {
-                    pass_some_expect = true;
-                    check_result(
-                        check_ts_not_have_deps_outside(file, allowed),
-                        &expect.error_msg,
-                    );
-                }
+fn check_any_of_expects<T>(
+    expects: &[T],
+    mut get_expect_result: impl FnMut(&T) -> ExpectResult,
+) -> Result<(), String> {
+    let mut closest: Option<(usize, ExpectResult)> = None;
 
-                if let Some(allowed) = &ts_expect.not_have_exports_used_outside {
-                    pass_some_expect = true;
-                    check_result(
-                        check_ts_not_have_used_exports_outside(file, allowed),
-                        &expect.error_msg,
-                    );
-                }
+    for (index, expect) in expects.iter().enumerate() {
+        let result = get_expect_result(expect);
 
-                if ts_expect.not_have_direct_circular_deps {
-                    pass_some_expect = true;
-                    check_result(
-                        check_ts_not_have_direct_circular_deps(file),
-                        &expect.error_msg,
-                    );
-                }
+        if result.errors.is_empty() {
+            return Ok(());
+        }
+
+        let is_closer = closest.as_ref().is_none_or(|(_, closest_result)| {
+            (result.passed_checks(), closest_result.errors.len())
+                > (closest_result.passed_checks(), result.errors.len())
+        });
+
+        if is_closer {
+            closest = Some((index, result));
+        }
+    }
+
+    let (closest_index, closest_result) = closest.unwrap_or_default();
+
+    Err(format!(
+        "should pass at least one of the `any_of` expectations, the closest one (#{}, {} of {} checks passed) failed with:{}",
+        closest_index + 1,
+        closest_result.passed_checks(),
+        closest_result.checks,
+        closest_result
+            .errors
+            .iter()
+            .map(|error| format!("\n   | {}", error))
+            .collect::<String>()
+    ))
+}
+
+fn check_not_expect(not_expect_result: &ExpectResult) -> Result<(), String> {
+    if not_expect_result.errors.is_empty() {
+        Err("should not pass the `not` expectation".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct FolderDepth {
+    from_root: usize,
+    from_config_folder: usize,
+}
+
+/// Number of folders between the root and the folder at `folder_path`
+fn get_folder_path_depth(folder_path: &str) -> usize {
+    folder_path.split('/').count() - 1
+}
+
+fn folder_matches_condition(
+    folder: &Folder,
+    folder_path: &str,
+    depth: FolderDepth,
+    conditions: &AnyOr<FolderConditions>,
+    acronyms: &Acronyms,
+) -> Option<ConditionsResult> {
+    match conditions {
+        AnyOr::Any => Some(ConditionsResult::default()),
+        AnyOr::Or(conditions) => folder_matches_conditions(
+            folder,
+            folder_path,
+            depth,
+            conditions,
+            acronyms,
+        ),
+    }
+}
+
+fn folder_matches_conditions(
+    folder: &Folder,
+    folder_path: &str,
+    depth: FolderDepth,
+    conditions: &FolderConditions,
+    acronyms: &Acronyms,
+) -> Option<ConditionsResult> {
+    let mut result_captures: Vec<Capture> = Vec::new();
+
+    if conditions.is_symlink && !folder.is_symlink {
+        return None;
+    }
+
+    let folder_depth = if conditions.depth_from_root {
+        depth.from_root
+    } else {
+        depth.from_config_folder
+    };
+
+    if let Some(depth_is) = conditions.depth_is {
+        if folder_depth != depth_is {
+            return None;
+        }
+    }
+
+    if let Some(depth_at_least) = conditions.depth_at_least {
+        if folder_depth < depth_at_least {
+            return None;
+        }
+    }
+
+    if let Some(pattern) = &conditions.has_name_case {
+        if name_case_is(&folder.name, pattern, acronyms).is_err() {
+            return None;
+        }
+    }
+
+    if let Some(pattern) = &conditions.has_name {
+        if let Ok(captures) = path_pattern_match(&folder.name, pattern) {
+            result_captures.extend(captures);
+        } else {
+            return None;
+        }
+    }
+
+    if let Some(find_pattern) = &conditions.root_files_find_pattern {
+        if let Ok(captures) = check_root_files_find_pattern(folder, find_pattern) {
+            result_captures.extend(captures);
+        } else {
+            return None;
+        }
+    }
+
+    if let Some(pattern) = &conditions.not_has_name {
+        if path_pattern_match(&folder.name, pattern).is_ok() {
+            return None;
+        }
+    }
+
+    if let Some(pattern) = &conditions.has_path {
+        if let Ok(captures) = root_path_pattern_match(folder_path, pattern) {
+            result_captures.extend(captures);
+        } else {
+            return None;
+        }
+    }
+
+    if let Some(pattern) = &conditions.not_has_path {
+        if root_path_pattern_match(folder_path, pattern).is_ok() {
+            return None;
+        }
+    }
+
+    if let Some(pattern) = &conditions.in_folder {
+        if let Ok(captures) =
+            in_folder_pattern_match(get_parent_path(folder_path), pattern)
+        {
+            result_captures.extend(captures);
+        } else {
+            return None;
+        }
+    }
+
+    if let Some(pattern) = &conditions.not_in_folder {
+        if in_folder_pattern_match(get_parent_path(folder_path), pattern).is_ok() {
+            return None;
+        }
+    }
+
+    if let Some(all_of) = &conditions.all_of {
+        for sub_conditions in all_of {
+            result_captures.extend(
+                folder_matches_conditions(
+                    folder,
+                    folder_path,
+                    depth,
+                    sub_conditions,
+                    acronyms,
+                )?
+                .captures,
+            );
+        }
+    }
 
-                if let Some(imports) = &ts_expect.have_imports {
-                    pass_some_expect = true;
-                    check_result(
-                        check_ts_have_imports(file, imports),
-                        &expect.error_msg,
-                    );
-                }
+    if let Some(any_of) = &conditions.any_of {
+        let matched = any_of.iter().find_map(|sub_conditions| {
+            folder_matches_conditions(
+                folder,
+                folder_path,
+                depth,
+                sub_conditions,
+                acronyms,
+            )
+        })?;
 
-                if let Some(not_have_imports) = &ts_expect.not_have_imports {
-                    pass_some_expect = true;
-                    check_result(
-                        check_ts_not_have_imports(file, not_have_imports),
-                        &expect.error_msg,
-                    );
-                }
-            }
+        result_captures.extend(matched.captures);
+    }
 
-            if cfg!(debug_assertions) && !pass_some_expect {
-                panic!("Unexpected expect {:#?}", expect);
-            }
+    if let Some(not) = &conditions.not {
+        if folder_matches_conditions(folder, folder_path, depth, not, acronyms)
+            .is_some()
+        {
+            return None;
         }
     }
 
-    if errors.is_empty() {
-        Ok(())
-    } else {
-        Err(errors)
-    }
+    Some(ConditionsResult {
+        captures: result_captures,
+    })
 }
 
-fn folder_matches_condition(
-    folder: &Folder,
-    conditions: &AnyOr<FolderConditions>,
-) -> Option<ConditionsResult> {
-    match conditions {
-        AnyOr::Any => Some(ConditionsResult::default()),
-        AnyOr::Or(conditions) => {
-            let mut result_captures: Vec<Capture> = Vec::new();
+#[derive(Debug, Default)]
+pub struct Problems {
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+    pub infos: Vec<String>,
+}
 
-            if let Some(pattern) = &conditions.has_name_case {
-                if name_case_is(&folder.name, pattern).is_err() {
-                    return None;
-                }
-            }
+impl Problems {
+    fn push(&mut self, severity: Severity, problem: String) {
+        match severity {
+            Severity::Off => {}
+            Severity::Info => self.infos.push(problem),
+            Severity::Warn => self.warnings.push(problem),
+            Severity::Error => self.errors.push(problem),
+        }
+    }
 
-            if let Some(pattern) = &conditions.has_name {
-                if let Ok(captures) = path_pattern_match(&folder.name, pattern) {
-                    result_captures.extend(captures);
-                } else {
-                    return None;
-                }
-            }
+    fn extend(&mut self, problems: Problems) {
+        self.errors.extend(problems.errors);
+        self.warnings.extend(problems.warnings);
+        self.infos.extend(problems.infos);
+    }
 
-            if let Some(find_pattern) = &conditions.root_files_find_pattern {
-                if let Ok(captures) =
-                    check_root_files_find_pattern(folder, find_pattern)
-                {
-                    result_captures.extend(captures);
-                } else {
-                    return None;
-                }
-            }
+    fn map(self, map_problem: impl Fn(String) -> String) -> Problems {
+        Problems {
+            errors: self.errors.into_iter().map(&map_problem).collect(),
+            warnings: self.warnings.into_iter().map(&map_problem).collect(),
+            infos: self.infos.into_iter().map(&map_problem).collect(),
+        }
+    }
 
-            if let Some(pattern) = &conditions.not_has_name {
-                if path_pattern_match(&folder.name, pattern).is_ok() {
-                    return None;
-                }
-            }
+    fn is_empty(&self) -> bool {
+        self.errors.is_empty() && self.warnings.is_empty() && self.infos.is_empty()
+    }
 
-            Some(ConditionsResult {
-                captures: result_captures,
-            })
+    fn into_result(self) -> Result<(), Problems> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
         }
     }
 }
 
-#[derive(Debug)]
-pub struct Problems {
-    pub errors: Vec<String>,
-    pub warnings: Vec<String>,
-}
-
 #[derive(Debug)]
 enum FolderExpectError {
-    Errors(Vec<String>),
+    /// The folder is considered invalid only if there are errors, the warnings
+    /// and infos of the expects don't stop the checks
+    Errors(Problems),
     ChildProblems(Problems),
 }
 
 #[allow(clippy::too_many_arguments)]
 fn check_folder_expected(
-    allow_warnings: bool,
+    options: &CheckOptions,
     folder: &Folder,
     expected: &AnyNoneOr<Vec<FolderExpect>>,
     conditions_result: &ConditionsResult,
@@ -415,97 +1138,56 @@ fn check_folder_expected(
     context_conditions: &[Capture],
     error_msg_vars: &ErrorMsgVars,
     is_test_config: bool,
+    get_severity: &dyn Fn(Option<Severity>) -> Severity,
 ) -> Result<(), FolderExpectError> {
     match expected {
-        AnyNoneOr::None => Err(FolderExpectError::Errors(vec![
-            "Folder is not expected".to_string(),
-        ])),
+        AnyNoneOr::None => {
+            let mut problems = Problems::default();
+            problems.push(get_severity(None), "Folder is not expected".to_string());
+
+            problems.into_result().map_err(FolderExpectError::Errors)
+        }
         AnyNoneOr::Any => Ok(()),
         AnyNoneOr::Or(expected) => {
-            let mut pass_some_expect = false;
-
             let captures =
                 clone_extend_vec(&conditions_result.captures, context_conditions);
 
+            let expected_folder_path = format!("{}/{}", folder_path, folder.name);
+
+            let mut expect_problems = Problems::default();
+
             for expect in expected {
-                if let Some(file_name_case_is) = &expect.name_case_is {
-                    pass_some_expect = true;
-                    append_error_to_vec(
-                        name_case_is(&folder.name, file_name_case_is),
-                        &expect.error_msg,
-                        error_msg_vars,
-                    )
-                    .map_err(FolderExpectError::Errors)?;
-                }
+                let severity = get_severity(expect.severity);
 
-                if let Some(name_is) = &expect.name_is {
-                    pass_some_expect = true;
-                    append_error_to_vec(
-                        check_path_pattern(&folder.name, name_is, &captures),
-                        &expect.error_msg,
-                        error_msg_vars,
-                    )
-                    .map_err(FolderExpectError::Errors)?;
+                if severity == Severity::Off {
+                    continue;
                 }
 
-                if let Some(name_is_not) = &expect.name_is_not {
-                    pass_some_expect = true;
-                    append_error_to_vec(
-                        check_negated_path_pattern(
-                            &folder.name,
-                            name_is_not,
-                            &captures,
-                        ),
-                        &expect.error_msg,
-                        error_msg_vars,
-                    )
-                    .map_err(FolderExpectError::Errors)?;
-                }
+                let expect_errors = get_folder_expect_result(
+                    folder,
+                    &expected_folder_path,
+                    expect,
+                    &captures,
+                    error_msg_vars,
+                    &options.config.acronyms,
+                )
+                .errors;
 
-                if let Some(root_files_has) = &expect.root_files_has {
-                    pass_some_expect = true;
-                    append_error_to_vec(
-                        check_root_files_has_pattern(
-                            folder,
-                            root_files_has,
-                            &captures,
-                        )
-                        .map(|_| ()),
-                        &expect.error_msg,
-                        error_msg_vars,
-                    )
-                    .map_err(FolderExpectError::Errors)?;
-                }
+                if !expect_errors.is_empty() {
+                    for error in expect_errors {
+                        expect_problems.push(severity, error);
+                    }
 
-                if let Some(root_files_has_not) = &expect.root_files_has_not {
-                    pass_some_expect = true;
-                    append_error_to_vec(
-                        check_negated_root_files_has_pattern(
-                            folder,
-                            root_files_has_not,
-                            &captures,
-                        ),
-                        &expect.error_msg,
-                        error_msg_vars,
-                    )
-                    .map_err(FolderExpectError::Errors)?;
-                }
+                    if severity == Severity::Error {
+                        return Err(FolderExpectError::Errors(expect_problems));
+                    }
 
-                if let Some(min_children) = &expect.have_min_children {
-                    pass_some_expect = true;
-                    append_error_to_vec(
-                        check_folder_min_children(folder, *min_children),
-                        &expect.error_msg,
-                        error_msg_vars,
-                    )
-                    .map_err(FolderExpectError::Errors)?;
+                    continue;
                 }
 
                 if let Some((folder_rules, file_rules)) = &expect.child_rules {
-                    pass_some_expect = true;
-
-                    check_folder_children(
-                        allow_warnings,
+                    if let Err(mut child_problems) = check_folder_children(
+                        options,
                         folder,
                         Some(&FolderConfig {
                             file_rules: file_rules.clone(),
@@ -519,6 +1201,7 @@ fn check_folder_expected(
                             unexpected_folders_error_msg: None,
                             unexpected_error_msg: None,
                             append_error_msg: None,
+                            case_collisions: None,
                         }),
                         folder_path.to_string(),
                         inherited_files_rules.to_vec(),
@@ -526,24 +1209,230 @@ fn check_folder_expected(
                         expand_to_capture_case_variation(
                             "context_folder",
                             folder.name.clone(),
+                            &options.config.acronyms,
                         ),
                         error_msg_vars,
                         is_test_config,
                         false,
                         false,
                         false,
-                    )
-                    .map_err(FolderExpectError::ChildProblems)?;
-                }
+                    ) {
+                        child_problems.extend(expect_problems);
 
-                if cfg!(debug_assertions) && !pass_some_expect {
-                    panic!("Unexpected expect {:#?}", expect);
+                        return Err(FolderExpectError::ChildProblems(
+                            child_problems,
+                        ));
+                    }
                 }
             }
 
-            Ok(())
+            expect_problems
+                .into_result()
+                .map_err(FolderExpectError::Errors)
+        }
+    }
+}
+
+fn get_folder_expect_result(
+    folder: &Folder,
+    folder_path: &str,
+    expect: &FolderExpect,
+    captures: &[Capture],
+    error_msg_vars: &ErrorMsgVars,
+    acronyms: &Acronyms,
+) -> ExpectResult {
+    let mut expect_result = ExpectResult::default();
+
+    let mut check_result =
+        |result: Result<(), String>, expect_error_msg: &Option<String>| {
+            expect_result.checks += 1;
+
+            if let Err(error) =
+                append_expect_error(result, expect_error_msg, error_msg_vars)
+            {
+                expect_result.errors.push(error);
+            }
+        };
+
+    let mut pass_some_expect = expect.child_rules.is_some();
+
+    if let Some(file_name_case_is) = &expect.name_case_is {
+        pass_some_expect = true;
+        check_result(
+            name_case_is(&folder.name, file_name_case_is, acronyms),
+            &expect.error_msg,
+        );
+    }
+
+    if let Some(name_is) = &expect.name_is {
+        pass_some_expect = true;
+        check_result(
+            check_path_pattern(&folder.name, name_is, captures, acronyms),
+            &expect.error_msg,
+        );
+    }
+
+    if let Some(name_is_not) = &expect.name_is_not {
+        pass_some_expect = true;
+        check_result(
+            check_negated_path_pattern(
+                &folder.name,
+                name_is_not,
+                captures,
+                acronyms,
+            ),
+            &expect.error_msg,
+        );
+    }
+
+    if let Some(unique_name) = &expect.name_is_unique_in {
+        pass_some_expect = true;
+        check_result(
+            name_is_unique_in(
+                &folder.name,
+                folder_path,
+                SiblingKind::Folder,
+                unique_name,
+            ),
+            &expect.error_msg,
+        );
+    }
+
+    if let Some(root_files_has) = &expect.root_files_has {
+        pass_some_expect = true;
+        check_result(
+            check_root_files_has_pattern(folder, root_files_has, captures, acronyms)
+                .map(|_| ()),
+            &expect.error_msg,
+        );
+    }
+
+    if let Some(root_files_has_not) = &expect.root_files_has_not {
+        pass_some_expect = true;
+        check_result(
+            check_negated_root_files_has_pattern(
+                folder,
+                root_files_has_not,
+                captures,
+                acronyms,
+            ),
+            &expect.error_msg,
+        );
+    }
+
+    if let Some(min_children) = &expect.have_min_children {
+        pass_some_expect = true;
+        check_result(
+            check_folder_min_children(folder, *min_children),
+            &expect.error_msg,
+        );
+    }
+
+    if let Some(placeholders) = &expect.is_not_empty {
+        pass_some_expect = true;
+        check_result(
+            check_folder_is_not_empty(folder, placeholders),
+            &expect.error_msg,
+        );
+    }
+
+    if let Some(max_children) = expect.max_children {
+        pass_some_expect = true;
+        check_result(
+            check_folder_max_children(folder, max_children, "children", |_| true),
+            &expect.error_msg,
+        );
+    }
+
+    if let Some(max_files) = expect.max_files {
+        pass_some_expect = true;
+        check_result(
+            check_folder_max_children(folder, max_files, "files", |child| {
+                matches!(child, FolderChild::FileChild(_))
+            }),
+            &expect.error_msg,
+        );
+    }
+
+    if let Some(max_folders) = expect.max_folders {
+        pass_some_expect = true;
+        check_result(
+            check_folder_max_children(folder, max_folders, "folders", |child| {
+                matches!(child, FolderChild::Folder(_))
+            }),
+            &expect.error_msg,
+        );
+    }
+
+    if let Some(max_depth) = expect.max_depth {
+        pass_some_expect = true;
+        check_result(check_folder_max_depth(folder, max_depth), &expect.error_msg);
+    }
+
+    if let Some(any_of) = &expect.any_of {
+        pass_some_expect = true;
+        check_result(
+            check_any_of_expects(any_of, |sub_expect| {
+                get_folder_expect_result(
+                    folder,
+                    folder_path,
+                    sub_expect,
+                    captures,
+                    error_msg_vars,
+                    acronyms,
+                )
+            }),
+            &expect.error_msg,
+        );
+    }
+
+    if let Some(not) = &expect.not {
+        pass_some_expect = true;
+        check_result(
+            check_not_expect(&get_folder_expect_result(
+                folder,
+                folder_path,
+                not,
+                captures,
+                error_msg_vars,
+                acronyms,
+            )),
+            &expect.error_msg,
+        );
+    }
+
+    if let Some(all_of) = &expect.all_of {
+        pass_some_expect = true;
+
+        for sub_expect in all_of {
+            let sub_result = get_folder_expect_result(
+                folder,
+                folder_path,
+                sub_expect,
+                captures,
+                error_msg_vars,
+                acronyms,
+            );
+
+            expect_result.checks += sub_result.checks;
+
+            for error in sub_result.errors {
+                if let Err(error) = append_expect_error(
+                    Err(error),
+                    &expect.error_msg,
+                    error_msg_vars,
+                ) {
+                    expect_result.errors.push(error);
+                }
+            }
         }
     }
+
+    if cfg!(debug_assertions) && !pass_some_expect {
+        panic!("Unexpected expect {:#?}", expect);
+    }
+
+    expect_result
 }
 
 #[derive(Debug, Clone)]
@@ -554,12 +1443,12 @@ struct InheritedFileRule {
 #[derive(Debug, Clone)]
 struct InheritedFolderRule {
     rule: FolderRule,
+    config_folder_depth: usize,
 }
 
 #[derive(Default)]
 struct ChildCheckOutcome {
-    errors: Vec<String>,
-    warnings: Vec<String>,
+    problems: Problems,
     matched_folder_name: Option<String>,
 }
 
@@ -588,7 +1477,7 @@ pub fn to_folder_config_name(name: &String) -> String {
 }
 
 fn check_folder_children(
-    allow_warnings: bool,
+    options: &CheckOptions,
     folder: &Folder,
     folder_config: Option<&FolderConfig>,
     folder_path: String,
@@ -606,17 +1495,17 @@ fn check_folder_children(
         folder_config,
         &inherited_files_rules,
         is_test_config,
+        &options.config.acronyms,
     );
 
     if let Err(error) = warm_file_deps_results_for_paths(&files_with_ts_checks) {
         return Err(Problems {
             errors: vec![error],
-            warnings: Vec::new(),
+            ..Default::default()
         });
     }
 
-    let mut errors: Vec<String> = Vec::new();
-    let mut warnings: Vec<String> = Vec::new();
+    let mut problems = Problems::default();
 
     let append_error = folder_config
         .and_then(|fc| {
@@ -662,7 +1551,7 @@ fn check_folder_children(
         .map(|(index, child)| {
             let outcome = match child {
                 FolderChild::FileChild(file) => check_file_child(
-                    allow_warnings,
+                    options,
                     file,
                     folder,
                     folder_config,
@@ -675,7 +1564,7 @@ fn check_folder_children(
                     &append_error,
                 ),
                 FolderChild::Folder(sub_folder) => check_sub_folder_child(
-                    allow_warnings,
+                    options,
                     sub_folder,
                     folder_config,
                     &folder_path,
@@ -702,27 +1591,22 @@ fn check_folder_children(
             folders_missing_check.remove(&folder_name);
         }
 
-        errors.extend(outcome.errors);
-        warnings.extend(outcome.warnings);
+        problems.extend(outcome.problems);
     }
 
     for folder_missing in folders_missing_check {
-        errors.push(format!(
+        problems.errors.push(format!(
             "Folder '/{}' is missing in folder '{}'{}",
             folder_missing, folder_path, append_error
         ));
     }
 
-    if !errors.is_empty() || !warnings.is_empty() {
-        Err(Problems { errors, warnings })
-    } else {
-        Ok(())
-    }
+    problems.into_result()
 }
 
 #[allow(clippy::too_many_arguments)]
 fn check_file_child(
-    allow_warnings: bool,
+    options: &CheckOptions,
     file: &File,
     folder: &Folder,
     folder_config: Option<&FolderConfig>,
@@ -734,8 +1618,23 @@ fn check_file_child(
     allow_unconfigured_files: bool,
     append_error: &str,
 ) -> ChildCheckOutcome {
-    let mut errors = Vec::new();
-    let mut warnings = Vec::new();
+    if let Some(error) = get_reported_symlink_error(
+        file.is_symlink,
+        options.config.symlinks,
+        file.name_with_ext.clone(),
+        folder_path,
+        append_error,
+    ) {
+        return ChildCheckOutcome {
+            problems: Problems {
+                errors: vec![error],
+                ..Default::default()
+            },
+            matched_folder_name: None,
+        };
+    }
+
+    let mut problems = Problems::default();
     let mut file_touched = false;
 
     let file_error_prefix = format!(
@@ -745,7 +1644,7 @@ fn check_file_child(
 
     let mut check_file_rule = |rule: &FileRule| {
         if let Some(conditions_result) =
-            file_matches_condition(file, &rule.conditions)
+            file_matches_condition(file, &rule.conditions, &options.config.acronyms)
         {
             if !rule.not_touch {
                 file_touched = true;
@@ -755,22 +1654,25 @@ fn check_file_child(
                 return;
             }
 
-            if let Err(expect_errors) = check_file_expect(
+            if let Err(expect_problems) = check_file_expect(
                 file,
                 &rule.expect,
                 folder,
                 &conditions_result,
                 context_conditions,
                 error_msg_vars,
+                &|expect_severity| {
+                    get_reported_severity(
+                        &rule.id,
+                        rule.severity,
+                        expect_severity,
+                        options,
+                    )
+                },
+                &options.config.acronyms,
             ) {
-                for error in expect_errors {
-                    let problem_vec = if allow_warnings && rule.is_warning {
-                        &mut warnings
-                    } else {
-                        &mut errors
-                    };
-
-                    problem_vec.push(format!(
+                problems.extend(expect_problems.map(|error| {
+                    format!(
                         "{}{}{}",
                         file_error_prefix,
                         if let Some(custom_error) = &rule.error_msg {
@@ -779,8 +1681,8 @@ fn check_file_child(
                             error
                         },
                         append_error
-                    ));
-                }
+                    )
+                }));
             }
         }
     };
@@ -801,9 +1703,11 @@ fn check_file_child(
             let mut one_of_matched = false;
 
             for rule in &one_of.rules {
-                if let Some(conditions_result) =
-                    file_matches_condition(file, &rule.conditions)
-                {
+                if let Some(conditions_result) = file_matches_condition(
+                    file,
+                    &rule.conditions,
+                    &options.config.acronyms,
+                ) {
                     one_of_matched_at_least_one_condition = true;
 
                     if !rule.not_touch {
@@ -817,6 +1721,8 @@ fn check_file_child(
                         &conditions_result,
                         context_conditions,
                         error_msg_vars,
+                        &get_one_of_severity,
+                        &options.config.acronyms,
                     )
                     .is_ok()
                     {
@@ -827,16 +1733,30 @@ fn check_file_child(
             }
 
             if one_of_matched_at_least_one_condition && !one_of_matched {
-                errors.push(format!(
-                    "{}{}{}",
-                    file_error_prefix, one_of.error_msg, append_error
-                ));
+                problems.push(
+                    get_reported_severity(
+                        &one_of.id,
+                        one_of.severity,
+                        None,
+                        options,
+                    ),
+                    format!(
+                        "{}{}{}",
+                        file_error_prefix, one_of.error_msg, append_error
+                    ),
+                );
             }
         }
     }
 
-    if !file_touched && !allow_unconfigured_files {
-        errors.push(format!(
+    if !file_touched
+        && !allow_unconfigured_files
+        && !is_unconfigured_dotfile_ignored(
+            &file.name_with_ext,
+            options.config.dotfiles,
+        )
+    {
+        problems.errors.push(format!(
             "File {} is not expected in folder {}{}{}",
             file.name_with_ext.bright_yellow(),
             folder_path.bright_red(),
@@ -853,15 +1773,14 @@ fn check_file_child(
     }
 
     ChildCheckOutcome {
-        errors,
-        warnings,
+        problems,
         matched_folder_name: None,
     }
 }
 
 #[allow(clippy::too_many_arguments)]
 fn check_sub_folder_child(
-    allow_warnings: bool,
+    options: &CheckOptions,
     sub_folder: &Folder,
     folder_config: Option<&FolderConfig>,
     folder_path: &str,
@@ -875,8 +1794,23 @@ fn check_sub_folder_child(
     allow_unconfigured_folders: bool,
     append_error: &str,
 ) -> ChildCheckOutcome {
-    let mut errors = Vec::new();
-    let mut warnings = Vec::new();
+    if let Some(error) = get_reported_symlink_error(
+        sub_folder.is_symlink,
+        options.config.symlinks,
+        format!("/{}", sub_folder.name),
+        folder_path,
+        append_error,
+    ) {
+        return ChildCheckOutcome {
+            problems: Problems {
+                errors: vec![error],
+                ..Default::default()
+            },
+            matched_folder_name: Some(sub_folder.name.clone()),
+        };
+    }
+
+    let mut problems = Problems::default();
     let mut matched_folder_name = None;
 
     let folder_error_prefix = format!(
@@ -899,6 +1833,10 @@ fn check_sub_folder_child(
     let sub_folder_inherited_files_rules =
         [inherited_files_rules.to_vec(), parent_file_rules].concat();
 
+    let sub_folder_path = format!("{}/{}", folder_path, sub_folder.name);
+    let parent_folder_depth = get_folder_path_depth(folder_path);
+    let sub_folder_depth = parent_folder_depth + 1;
+
     let parent_folder_rules: Vec<InheritedFolderRule> =
         folder_config.map_or(Vec::new(), |folder_config| {
             folder_config
@@ -906,7 +1844,10 @@ fn check_sub_folder_child(
                 .iter()
                 .filter_map(|rule| match rule.non_recursive {
                     true => None,
-                    false => Some(InheritedFolderRule { rule: rule.clone() }),
+                    false => Some(InheritedFolderRule {
+                        rule: rule.clone(),
+                        config_folder_depth: parent_folder_depth,
+                    }),
                 })
                 .collect()
         });
@@ -919,8 +1860,17 @@ fn check_sub_folder_child(
     let mut folder_rules_allow_unexpected_files = false;
     let mut folder_rules_allow_unexpected_folders = false;
 
-    let mut check_folder_rule = |rule: &FolderRule| {
-        let folder_matches = folder_matches_condition(sub_folder, &rule.conditions);
+    let mut check_folder_rule = |rule: &FolderRule, config_folder_depth: usize| {
+        let folder_matches = folder_matches_condition(
+            sub_folder,
+            &sub_folder_path,
+            FolderDepth {
+                from_root: sub_folder_depth,
+                from_config_folder: sub_folder_depth - config_folder_depth,
+            },
+            &rule.conditions,
+            &options.config.acronyms,
+        );
 
         if let Some(conditions_result) = folder_matches {
             matched_folder_name = Some(sub_folder.name.clone());
@@ -938,7 +1888,7 @@ fn check_sub_folder_child(
             }
 
             if let Err(folder_expect_error) = check_folder_expected(
-                allow_warnings,
+                options,
                 sub_folder,
                 &rule.expect,
                 &conditions_result,
@@ -948,63 +1898,129 @@ fn check_sub_folder_child(
                 context_conditions,
                 error_msg_vars,
                 is_test_config,
+                &|expect_severity| {
+                    get_reported_severity(
+                        &rule.id,
+                        rule.severity,
+                        expect_severity,
+                        options,
+                    )
+                },
             ) {
-                match folder_expect_error {
-                    FolderExpectError::Errors(problems_found) => {
-                        if !problems_found.is_empty() {
+                let rule_problems = match folder_expect_error {
+                    FolderExpectError::Errors(expect_problems) => {
+                        if !expect_problems.errors.is_empty() {
                             folder_has_error = true;
-
-                            let problem_vec = if rule.is_warning && allow_warnings {
-                                &mut warnings
-                            } else {
-                                &mut errors
-                            };
-
-                            push_to_folder_problem_vec(
-                                problems_found,
-                                problem_vec,
-                                &folder_error_prefix,
-                                rule,
-                                append_error,
-                            );
                         }
+
+                        expect_problems
                     }
                     FolderExpectError::ChildProblems(child_problems) => {
-                        push_to_folder_problem_vec(
-                            child_problems.errors,
-                            &mut errors,
-                            &folder_error_prefix,
-                            rule,
-                            append_error,
-                        );
-
-                        push_to_folder_problem_vec(
-                            child_problems.warnings,
-                            &mut warnings,
-                            &folder_error_prefix,
-                            rule,
-                            append_error,
-                        );
+                        child_problems
                     }
-                }
+                };
+
+                problems.extend(rule_problems.map(|error| {
+                    get_folder_problem(
+                        error,
+                        &folder_error_prefix,
+                        rule,
+                        append_error,
+                    )
+                }));
             }
         }
     };
 
     if let Some(folder_config) = folder_config {
         for rule in &folder_config.folder_rules {
-            check_folder_rule(rule);
+            check_folder_rule(rule, parent_folder_depth);
         }
     }
 
     for inherited_rule in inherited_folders_rules {
-        check_folder_rule(&inherited_rule.rule);
+        check_folder_rule(&inherited_rule.rule, inherited_rule.config_folder_depth);
+    }
+
+    if let Some(folder_config) = folder_config {
+        for one_of in &folder_config.one_of_blocks.folder_blocks {
+            let mut one_of_matched_at_least_one_condition = false;
+            let mut one_of_matched = false;
+
+            for rule in &one_of.rules {
+                if let Some(conditions_result) = folder_matches_condition(
+                    sub_folder,
+                    &sub_folder_path,
+                    FolderDepth {
+                        from_root: sub_folder_depth,
+                        from_config_folder: 1,
+                    },
+                    &rule.conditions,
+                    &options.config.acronyms,
+                ) {
+                    one_of_matched_at_least_one_condition = true;
+                    matched_folder_name = Some(sub_folder.name.clone());
+
+                    if !rule.not_touch {
+                        folder_touched = true;
+                    }
+
+                    if check_folder_expected(
+                        options,
+                        sub_folder,
+                        &rule.expect,
+                        &conditions_result,
+                        folder_path,
+                        &sub_folder_inherited_files_rules,
+                        &sub_folder_inherited_folders_rules,
+                        context_conditions,
+                        error_msg_vars,
+                        is_test_config,
+                        &get_one_of_severity,
+                    )
+                    .is_ok()
+                    {
+                        one_of_matched = true;
+
+                        if rule.allow_unexpected_files {
+                            folder_rules_allow_unexpected_files = true;
+                        }
+
+                        if rule.allow_unexpected_folders {
+                            folder_rules_allow_unexpected_folders = true;
+                        }
+
+                        break;
+                    }
+                }
+            }
+
+            if one_of_matched_at_least_one_condition && !one_of_matched {
+                let severity = get_reported_severity(
+                    &one_of.id,
+                    one_of.severity,
+                    None,
+                    options,
+                );
+
+                if severity == Severity::Error {
+                    folder_has_error = true;
+                }
+
+                problems.push(
+                    severity,
+                    format!(
+                        "{}{}{}",
+                        folder_error_prefix, one_of.error_msg, append_error
+                    ),
+                );
+            }
+        }
     }
 
     if folder_has_error {
         return ChildCheckOutcome {
-            errors,
-            warnings,
+            problems,
             matched_folder_name,
         };
     }
@@ -1021,13 +2037,23 @@ fn check_sub_folder_child(
         format!("{}/{}", folder_path, sub_folder.name)
     };
 
+    if sub_folder_cfg.is_none()
+        && !folder_touched
+        && is_unconfigured_dotfile_ignored(&sub_folder.name, options.config.dotfiles)
+    {
+        return ChildCheckOutcome {
+            problems,
+            matched_folder_name,
+        };
+    }
+
     let mut folder_is_not_expected = false;
 
     if sub_folder_cfg.is_some() {
         matched_folder_name = Some(sub_folder.name.clone());
     } else if !folder_touched && !allow_unconfigured_folders {
         folder_is_not_expected = true;
-        errors.push(format!(
+        problems.errors.push(format!(
             "Folder {} is not expected in folder {}{}{}",
             format!("/{}", sub_folder.name).bright_red(),
             folder_path.bright_red(),
@@ -1086,11 +2112,8 @@ fn check_sub_folder_child(
     }
 
     if !folder_is_not_expected {
-        if let Err(Problems {
-            errors: extra_errors,
-            warnings: extra_warnings,
-        }) = check_folder_children(
-            allow_warnings,
+        if let Err(sub_folder_problems) = check_folder_children(
+            options,
             sub_folder,
             new_sub_folder_cfg.as_ref(),
             parent_path,
@@ -1103,14 +2126,12 @@ fn check_sub_folder_child(
             child_inherited_allow_unconfigured_files,
             child_inherited_allow_unconfigured_folders,
         ) {
-            errors.extend(extra_errors);
-            warnings.extend(extra_warnings);
+            problems.extend(sub_folder_problems);
         }
     }
 
     ChildCheckOutcome {
-        errors,
-        warnings,
+        problems,
         matched_folder_name,
     }
 }
@@ -1120,6 +2141,7 @@ fn get_folder_files_with_ts_checks(
     folder_config: Option<&FolderConfig>,
     inherited_files_rules: &[InheritedFileRule],
     is_test_config: bool,
+    acronyms: &Acronyms,
 ) -> Vec<String> {
     let mut file_paths = Vec::new();
 
@@ -1132,7 +2154,7 @@ fn get_folder_files_with_ts_checks(
 
         if let Some(folder_config) = folder_config {
             for rule in &folder_config.file_rules {
-                if is_file_ts_rule_match(file, rule, is_test_config) {
+                if is_file_ts_rule_match(file, rule, is_test_config, acronyms) {
                     should_warm = true;
                     break;
                 }
@@ -1140,11 +2162,9 @@ fn get_folder_files_with_ts_checks(
 
             if !should_warm {
                 for one_of in &folder_config.one_of_blocks.file_blocks {
-                    if one_of
-                        .rules
-                        .iter()
-                        .any(|rule| is_file_ts_rule_match(file, rule, false))
-                    {
+                    if one_of.rules.iter().any(|rule| {
+                        is_file_ts_rule_match(file, rule, false, acronyms)
+                    }) {
                         should_warm = true;
                         break;
                     }
@@ -1154,8 +2174,12 @@ fn get_folder_files_with_ts_checks(
 
         if !should_warm {
             for inherited_rule in inherited_files_rules {
-                if is_file_ts_rule_match(file, &inherited_rule.rule, is_test_config)
-                {
+                if is_file_ts_rule_match(
+                    file,
+                    &inherited_rule.rule,
+                    is_test_config,
+                    acronyms,
+                ) {
                     should_warm = true;
                     break;
                 }
@@ -1174,6 +2198,7 @@ fn is_file_ts_rule_match(
     file: &File,
     rule: &FileRule,
     is_test_config: bool,
+    acronyms: &Acronyms,
 ) -> bool {
     if !rule_uses_ts_expect(rule) {
         return false;
@@ -1183,7 +2208,7 @@ fn is_file_ts_rule_match(
         return false;
     }
 
-    file_matches_condition(file, &rule.conditions).is_some()
+    file_matches_condition(file, &rule.conditions, acronyms).is_some()
 }
 
 fn rule_uses_ts_expect(rule: &FileRule) -> bool {
@@ -1194,6 +2219,18 @@ fn rule_uses_ts_expect(rule: &FileRule) -> bool {
 }
 
 fn expect_uses_ts_checks(expect: &FileExpect) -> bool {
+    let nested_expects_use_ts_checks = expect
+        .all_of
+        .iter()
+        .chain(expect.any_of.iter())
+        .flatten()
+        .chain(expect.not.as_deref())
+        .any(expect_uses_ts_checks);
+
+    if nested_expects_use_ts_checks {
+        return true;
+    }
+
     let Some(ts_expect) = &expect.ts else {
         return false;
     };
@@ -1208,25 +2245,22 @@ fn expect_uses_ts_checks(expect: &FileExpect) -> bool {
         || ts_expect.not_have_imports.is_some()
 }
 
-fn push_to_folder_problem_vec(
-    problems_found: Vec<String>,
-    problem_vec: &mut Vec<String>,
+fn get_folder_problem(
+    error: String,
     folder_error_prefix: &str,
     rule: &FolderRule,
     append_error: &str,
-) {
-    for error in problems_found {
-        problem_vec.push(format!(
-            "{}{}{}",
-            folder_error_prefix,
-            if let Some(custom_error) = &rule.error_msg {
-                format!("{}\n   | {}", custom_error, error)
-            } else {
-                error
-            },
-            append_error
-        ));
-    }
+) -> String {
+    format!(
+        "{}{}{}",
+        folder_error_prefix,
+        if let Some(custom_error) = &rule.error_msg {
+            format!("{}\n   | {}", custom_error, error)
+        } else {
+            error
+        },
+        append_error
+    )
 }
 
 pub fn check_root_folder(
@@ -1235,8 +2269,27 @@ pub fn check_root_folder(
     is_test_config: bool,
     allow_warnings: bool,
 ) -> Result<(), Problems> {
-    check_folder_children(
-        allow_warnings || config.allow_warnings,
+    set_project_paths(folder);
+    clear_json_schema_validators();
+
+    let mut tree_errors =
+        get_case_collisions_errors(folder, Some(&config.root_folder), ".", false);
+
+    if let Some(placeholders) = &config.report_empty_folders {
+        tree_errors.extend(get_empty_folders_errors(folder, ".", placeholders));
+    }
+
+    if let Some(duplicate_content) = &config.duplicate_content {
+        tree_errors.extend(get_duplicate_content_errors(duplicate_content));
+    }
+
+    let options = CheckOptions {
+        config,
+        allow_warnings: allow_warnings || config.allow_warnings,
+    };
+
+    let result = check_folder_children(
+        &options,
         folder,
         Some(&config.root_folder),
         String::from("."),
@@ -1248,9 +2301,21 @@ pub fn check_root_folder(
         false,
         false,
         false,
-    )
+    );
+
+    if tree_errors.is_empty() {
+        return result;
+    }
+
+    let mut problems = result.err().unwrap_or_default();
+
+    problems.errors.extend(tree_errors);
+
+    Err(problems)
 }
 
 mod checks;
+mod data_checks;
+mod markdown_checks;
 #[cfg(test)]
 mod tests;
@@ -1,52 +1,228 @@
 use crate::{
     check_folders::{Folder, FolderChild},
-    internal_config::{ContentMatches, NameCase, RootFilesFindPattern},
+    internal_config::{
+        Acronyms, ContentMatches, ContentNotMatches, LineScope, NameCase,
+        RootFilesFindPattern, SiblingPattern, UniqueNameScope,
+    },
     load_folder_structure::File,
     utils::wrap_vec_string_items_in,
 };
 use convert_case::{Case, Casing};
+use lazy_static::lazy_static;
 use regex::{escape, Regex};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Mutex,
+};
+
+/// Paths of all the project files and folders, used by the checks that look
+/// outside the checked folder
+#[derive(Debug, Default)]
+struct ProjectPaths {
+    files: HashSet<String>,
+    files_by_name: HashMap<String, Vec<String>>,
+    folders_by_name: HashMap<String, Vec<String>>,
+    /// Files with the same size and content hash, they are only duplicates if
+    /// their bytes are also equal
+    files_by_content_hash: HashMap<(u64, u64), Vec<File>>,
+    /// Groups of files with equal content, sorted by path
+    duplicated_content_groups: Vec<Vec<String>>,
+    folders: HashSet<String>,
+    /// JSON and YAML files, they can be used as schemas by the `json_schema`
+    /// expect
+    data_files: HashMap<String, File>,
+}
 
-pub fn name_case_is(name: &str, name_case_is: &NameCase) -> Result<(), String> {
-    match name_case_is {
-        NameCase::Kebab => {
-            let kebab_case_regex = Regex::new(r"^[a-z0-9][a-z0-9-.]+$").unwrap();
+lazy_static! {
+    static ref PROJECT_PATHS: Mutex<ProjectPaths> =
+        Mutex::new(ProjectPaths::default());
+}
 
-            if !kebab_case_regex.is_match(name) {
-                return Err("should be named in kebab-case".to_string());
+fn collect_project_paths(
+    folder: &Folder,
+    folder_path: &str,
+    project_paths: &mut ProjectPaths,
+) {
+    for child in &folder.children {
+        match child {
+            FolderChild::FileChild(file) => {
+                project_paths.files.insert(file.relative_path.clone());
+                project_paths
+                    .files_by_name
+                    .entry(file.name().to_string())
+                    .or_default()
+                    .push(file.relative_path.clone());
+
+                if matches!(file.extension.as_deref(), Some("json" | "yaml" | "yml"))
+                {
+                    project_paths
+                        .data_files
+                        .insert(file.relative_path.clone(), file.clone());
+                }
+
+                if let Some(content_hash) = file.content_hash {
+                    project_paths
+                        .files_by_content_hash
+                        .entry((file.size, content_hash))
+                        .or_default()
+                        .push(file.clone());
+                }
             }
-        }
-        NameCase::Camel => {
-            let camel_case_regex = Regex::new(r"^[a-z][a-zA-Z0-9.]+$").unwrap();
+            FolderChild::Folder(sub_folder) => {
+                let sub_folder_path = format!("{}/{}", folder_path, sub_folder.name);
+
+                project_paths.folders.insert(sub_folder_path.clone());
 
-            if !camel_case_regex.is_match(name) {
-                return Err("should be named in camelCase".to_string());
+                project_paths
+                    .folders_by_name
+                    .entry(sub_folder.name.clone())
+                    .or_default()
+                    .push(sub_folder_path.clone());
+
+                collect_project_paths(sub_folder, &sub_folder_path, project_paths);
             }
         }
-        NameCase::Snake => {
-            let snake_case_regex = Regex::new(r"^[a-z0-9][a-z0-9_.]+$").unwrap();
+    }
+}
 
-            if !snake_case_regex.is_match(name) {
-                return Err("should be named in snake_case".to_string());
-            }
+pub fn set_project_paths(root_folder: &Folder) {
+    let mut project_paths = ProjectPaths::default();
+
+    collect_project_paths(root_folder, ".", &mut project_paths);
+
+    project_paths.duplicated_content_groups =
+        get_equal_content_groups(&project_paths.files_by_content_hash);
+
+    *PROJECT_PATHS.lock().unwrap() = project_paths;
+}
+
+/// Checks if a `./` prefixed path is a file or folder of the project, `.` is
+/// the root folder
+pub fn project_path_exists(path: &str) -> bool {
+    let project_paths = PROJECT_PATHS.lock().unwrap();
+
+    path == "."
+        || project_paths.files.contains(path)
+        || project_paths.folders.contains(path)
+}
+
+pub fn get_project_data_file(path: &str) -> Option<File> {
+    PROJECT_PATHS.lock().unwrap().data_files.get(path).cloned()
+}
+
+fn get_name_case_regex(name_case: &NameCase) -> Regex {
+    let pattern = match name_case {
+        NameCase::Kebab => r"^[a-z0-9][a-z0-9-.]*$",
+        NameCase::Camel => r"^[a-z][a-zA-Z0-9.]*$",
+        NameCase::Snake => r"^[a-z0-9][a-z0-9_.]*$",
+        NameCase::Pascal => r"^[A-Z][a-zA-Z0-9.]*$",
+        NameCase::Constant => r"^[A-Z][A-Z0-9_.]*$",
+        NameCase::Dot => r"^[a-z0-9][a-z0-9.]*$",
+        NameCase::Train => r"^[A-Z][a-z0-9.]*(-[A-Z0-9][a-z0-9.]*)*$",
+        NameCase::Title => r"^[A-Z][a-z0-9.]*( [A-Z0-9][a-z0-9.]*)*$",
+        NameCase::Lower => r"^[^A-Z]+$",
+        NameCase::Flat => r"^[a-z0-9]+$",
+        // custom regexes are validated when the config is loaded
+        NameCase::Custom { regex, .. } => regex,
+    };
+
+    Regex::new(pattern).unwrap()
+}
+
+fn is_composed_of_acronyms(value: &str, acronyms: &[String]) -> bool {
+    value.is_empty()
+        || acronyms.iter().any(|acronym| {
+            value
+                .strip_prefix(acronym.as_str())
+                .is_some_and(|rest| is_composed_of_acronyms(rest, acronyms))
+        })
+}
+
+/// Checks the uppercase runs of camelCase and PascalCase names, e.g. in
+/// `URLParser` the `URL` run should be a declared acronym and, in strict
+/// mode, `UrlParser` is rejected because `URL` is not written in uppercase
+fn uses_valid_acronyms(name: &str, acronyms: &Acronyms) -> bool {
+    let chars: Vec<char> = name.chars().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        if !chars[i].is_ascii_uppercase() {
+            i += 1;
+            continue;
         }
-        NameCase::Pascal => {
-            let pascal_case_regex = Regex::new(r"^[A-Z][a-zA-Z0-9.]+$").unwrap();
 
-            if !pascal_case_regex.is_match(name) {
-                return Err("should be named in PascalCase".to_string());
+        let start = i;
+
+        while i < chars.len() && chars[i].is_ascii_uppercase() {
+            i += 1;
+        }
+
+        let mut end = i;
+
+        // the last uppercase char starts the next word, e.g. the `P` in `URLParser`
+        if i < chars.len() && chars[i].is_ascii_lowercase() {
+            end -= 1;
+        }
+
+        if end - start > 1 {
+            let run: String = chars[start..end].iter().collect();
+
+            if !is_composed_of_acronyms(&run, &acronyms.list) {
+                return false;
             }
         }
-        NameCase::Constant => {
-            let constant_case_regex = Regex::new(r"^[A-Z][A-Z0-9_.]+$").unwrap();
+    }
+
+    if acronyms.strict {
+        for acronym in &acronyms.list {
+            let capitalized = acronym.to_case(Case::Pascal);
+
+            for (index, _) in name.match_indices(&capitalized) {
+                let next_char = name[index + capitalized.len()..].chars().next();
 
-            if !constant_case_regex.is_match(name) {
-                return Err("should be named in CONSTANT_CASE".to_string());
+                if !next_char.is_some_and(|char| char.is_ascii_lowercase()) {
+                    return false;
+                }
             }
         }
     }
 
-    Ok(())
+    true
+}
+
+fn name_matches_case(name: &str, name_case: &NameCase, acronyms: &Acronyms) -> bool {
+    if !get_name_case_regex(name_case).is_match(name) {
+        return false;
+    }
+
+    match name_case {
+        NameCase::Camel | NameCase::Pascal if !acronyms.list.is_empty() => {
+            uses_valid_acronyms(name, acronyms)
+        }
+        _ => true,
+    }
+}
+
+pub fn name_case_is(
+    name: &str,
+    name_cases: &[NameCase],
+    acronyms: &Acronyms,
+) -> Result<(), String> {
+    if name_cases
+        .iter()
+        .any(|name_case| name_matches_case(name, name_case, acronyms))
+    {
+        return Ok(());
+    }
+
+    Err(format!(
+        "should be named in {}",
+        name_cases
+            .iter()
+            .map(|name_case| name_case.name())
+            .collect::<Vec<&str>>()
+            .join(" or ")
+    ))
 }
 
 pub fn extension_is(
@@ -65,6 +241,22 @@ pub fn extension_is(
     Ok(())
 }
 
+pub fn suffix_is(
+    file_suffixes: &[&str],
+    suffix_is: &[String],
+) -> Result<(), String> {
+    let file_suffix = file_suffixes.join(".");
+
+    if !suffix_is.contains(&file_suffix) {
+        return Err(format!(
+            "should have suffix {}",
+            wrap_vec_string_items_in(suffix_is, "'").join(" or ")
+        ));
+    }
+
+    Ok(())
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct Capture {
     pub name: String,
@@ -140,21 +332,49 @@ fn regex_match(regex: &Regex, str: &str) -> Result<Vec<Capture>, String> {
 }
 
 #[derive(Default)]
-struct ContextVars {
+pub struct ContextVars {
     pub folder_name: Option<String>,
 }
 
-pub fn expand_to_capture_case_variation(name: &str, value: String) -> Vec<Capture> {
+fn to_acronym_aware_case(value: &str, case: Case, acronyms: &Acronyms) -> String {
+    if acronyms.list.is_empty() {
+        return value.to_case(case);
+    }
+
+    value
+        .to_case(Case::Kebab)
+        .split('-')
+        .enumerate()
+        .map(|(i, word)| {
+            if i == 0 && case == Case::Camel {
+                word.to_string()
+            } else if acronyms.list.contains(&word.to_uppercase()) {
+                word.to_uppercase()
+            } else {
+                word.to_case(Case::Pascal)
+            }
+        })
+        .collect()
+}
+
+pub fn expand_to_capture_case_variation(
+    name: &str,
+    value: String,
+    acronyms: &Acronyms,
+) -> Vec<Capture> {
     let mut result = vec![];
 
     result.extend([
         Capture::new(name, &value),
-        Capture::new(&format!("{}_camelCase", name), &value.to_case(Case::Camel)),
+        Capture::new(
+            &format!("{}_camelCase", name),
+            &to_acronym_aware_case(&value, Case::Camel, acronyms),
+        ),
         Capture::new(&format!("{}_kebab-case", name), &value.to_case(Case::Kebab)),
         Capture::new(&format!("{}_snake_case", name), &value.to_case(Case::Snake)),
         Capture::new(
             &format!("{}_PascalCase", name),
-            &value.to_case(Case::Pascal),
+            &to_acronym_aware_case(&value, Case::Pascal, acronyms),
         ),
         Capture::new(
             &format!("{}_CONSTANT_CASE", name),
@@ -165,10 +385,11 @@ pub fn expand_to_capture_case_variation(name: &str, value: String) -> Vec<Captur
     result
 }
 
-fn replace_with_captures(
+pub fn replace_with_captures(
     pattern: &String,
     captures: &[Capture],
     context_vars: ContextVars,
+    acronyms: &Acronyms,
 ) -> String {
     let mut result = pattern.to_owned();
 
@@ -178,12 +399,16 @@ fn replace_with_captures(
         captures.extend(expand_to_capture_case_variation(
             &capture.raw_name,
             capture.value.clone(),
+            acronyms,
         ));
     }
 
     if let Some(folder_name) = context_vars.folder_name {
-        captures
-            .extend(expand_to_capture_case_variation("folder_name", folder_name));
+        captures.extend(expand_to_capture_case_variation(
+            "folder_name",
+            folder_name,
+            acronyms,
+        ));
     }
 
     for capture in captures.iter() {
@@ -193,34 +418,366 @@ fn replace_with_captures(
     result
 }
 
-pub fn has_sibling_file(
-    sibling_file_pattern: &String,
+#[derive(Debug, Clone, Copy)]
+pub enum SiblingKind {
+    File,
+    Folder,
+}
+
+impl SiblingKind {
+    fn label(&self) -> &str {
+        match self {
+            SiblingKind::File => "file",
+            SiblingKind::Folder => "folder",
+        }
+    }
+}
+
+/// Returns the names of the siblings that match the regex, the file itself is
+/// only considered a sibling if `match_file_itself` is true
+fn find_siblings<'a>(
+    file: &File,
+    folder: &'a Folder,
+    kind: SiblingKind,
+    regex: &Regex,
+    match_file_itself: bool,
+) -> Vec<&'a str> {
+    folder
+        .children
+        .iter()
+        .filter_map(|child| match (child, kind) {
+            (FolderChild::FileChild(sibling), SiblingKind::File)
+                if match_file_itself
+                    || sibling.name_with_ext != file.name_with_ext =>
+            {
+                Some(sibling.name_with_ext.as_str())
+            }
+            (FolderChild::Folder(sibling), SiblingKind::Folder) => {
+                Some(sibling.name.as_str())
+            }
+            _ => None,
+        })
+        .filter(|name| regex.is_match(name))
+        .collect()
+}
+
+pub fn has_sibling(
+    file: &File,
     folder: &Folder,
+    kind: SiblingKind,
+    sibling: &SiblingPattern,
     condition_captures: &[Capture],
+    acronyms: &Acronyms,
 ) -> Result<(), String> {
     let (pattern, regex) =
-        normalize_check_pattern(condition_captures, sibling_file_pattern)?;
+        normalize_check_pattern(condition_captures, &sibling.pattern, acronyms)?;
 
-    for child in &folder.children {
-        if let FolderChild::FileChild(file) = child {
-            if regex.is_match(&file.name_with_ext) {
-                return Ok(());
+    let num_of_siblings =
+        find_siblings(file, folder, kind, &regex, sibling.match_file_itself).len();
+
+    if num_of_siblings < sibling.at_least {
+        if sibling.at_least == 1 {
+            return Err(format!(
+                "should have a sibling {} matching pattern '{}'",
+                kind.label(),
+                pattern
+            ));
+        }
+
+        return Err(format!(
+            "should have at least {} sibling {}s matching pattern '{}', found {}",
+            sibling.at_least,
+            kind.label(),
+            pattern,
+            num_of_siblings
+        ));
+    }
+
+    if let Some(at_most) = sibling.at_most {
+        if num_of_siblings > at_most {
+            return Err(format!(
+                "should have at most {} sibling {}s matching pattern '{}', found {}",
+                at_most,
+                kind.label(),
+                pattern,
+                num_of_siblings
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn not_has_sibling(
+    file: &File,
+    folder: &Folder,
+    kind: SiblingKind,
+    sibling_pattern: &String,
+    condition_captures: &[Capture],
+    acronyms: &Acronyms,
+) -> Result<(), String> {
+    let (pattern, regex) =
+        normalize_check_pattern(condition_captures, sibling_pattern, acronyms)?;
+
+    match find_siblings(file, folder, kind, &regex, false).first() {
+        Some(sibling_name) => Err(format!(
+            "should not have a sibling {} matching pattern '{}', found '{}'",
+            kind.label(),
+            pattern,
+            sibling_name
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Resolves a path relative to the file folder, or to the root folder if it
+/// starts with `/`, returns `None` if it points outside the root folder
+pub fn resolve_file_relative_path(file: &File, path: &str) -> Option<String> {
+    let (mut segments, relative_path): (Vec<&str>, &str) =
+        match path.strip_prefix('/') {
+            Some(root_relative_path) => (vec![], root_relative_path),
+            None => {
+                let mut file_segments: Vec<&str> = file
+                    .relative_path
+                    .trim_start_matches("./")
+                    .split('/')
+                    .collect();
+
+                file_segments.pop();
+
+                (file_segments, path)
+            }
+        };
+
+    for segment in relative_path.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop()?;
             }
+            _ => segments.push(segment),
         }
     }
 
-    Err(format!(
-        "should have a sibling file matching pattern '{}'",
-        pattern
-    ))
+    Some(format!("./{}", segments.join("/")))
+}
+
+pub fn has_companion_file(
+    file: &File,
+    path_template: &String,
+    condition_captures: &[Capture],
+    acronyms: &Acronyms,
+) -> Result<(), String> {
+    let path = file.relative_path.trim_start_matches("./");
+
+    let path_without_ext = match &file.extension {
+        Some(extension) => path
+            .strip_suffix(&format!(".{}", extension))
+            .unwrap_or(path),
+        None => path,
+    };
+
+    let mut captures = condition_captures.to_vec();
+
+    captures.push(Capture {
+        name: "${path_without_ext}".to_string(),
+        raw_name: "path_without_ext".to_string(),
+        value: path_without_ext.to_string(),
+    });
+
+    let companion_path = replace_with_captures(
+        path_template,
+        &captures,
+        ContextVars::default(),
+        acronyms,
+    );
+
+    let resolved_path = match resolve_file_relative_path(file, &companion_path) {
+        Some(resolved_path) => resolved_path,
+        None => {
+            return Err(format!(
+                "should have a companion file at '{}', but the path is outside the root folder",
+                companion_path
+            ))
+        }
+    };
+
+    if PROJECT_PATHS.lock().unwrap().files.contains(&resolved_path) {
+        Ok(())
+    } else {
+        Err(format!(
+            "should have a companion file at '{}'",
+            resolved_path
+        ))
+    }
+}
+
+pub fn is_in_scope(path: &str, scope: &str) -> bool {
+    if scope.starts_with("regex:") || scope.contains('*') {
+        return root_path_pattern_match(path, scope).is_ok();
+    }
+
+    let scope_folder = scope.trim_start_matches("./").trim_matches('/');
+
+    scope_folder.is_empty()
+        || path
+            .trim_start_matches("./")
+            .starts_with(&format!("{}/", scope_folder))
+}
+
+/// Checks that no other file or folder in the scope has the same name, the
+/// files are compared by their name without suffixes and extension. `path` is
+/// the `./` prefixed path of the checked file or folder
+pub fn name_is_unique_in(
+    name: &str,
+    path: &str,
+    kind: SiblingKind,
+    unique_name: &UniqueNameScope,
+) -> Result<(), String> {
+    let full_name = path.rsplit('/').next().unwrap_or(name);
+
+    if unique_name.ignore.iter().any(|pattern| {
+        path_pattern_match(name, pattern).is_ok()
+            || path_pattern_match(full_name, pattern).is_ok()
+    }) {
+        return Ok(());
+    }
+
+    let project_paths = PROJECT_PATHS.lock().unwrap();
+
+    let paths_by_name = match kind {
+        SiblingKind::File => &project_paths.files_by_name,
+        SiblingKind::Folder => &project_paths.folders_by_name,
+    };
+
+    let duplicated_paths: Vec<&str> = paths_by_name
+        .get(name)
+        .map(|paths| {
+            paths
+                .iter()
+                .filter(|other_path| {
+                    *other_path != path
+                        && is_in_scope(other_path, &unique_name.scope)
+                })
+                .map(|other_path| other_path.as_str())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if duplicated_paths.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "{} name should be unique in '{}', also found in: {}",
+            kind.label(),
+            unique_name.scope,
+            duplicated_paths.join(", ")
+        ))
+    }
 }
 
-fn normalize_check_pattern(
+/// Splits the files with the same size and content hash by their bytes, so
+/// hash collisions are not reported as duplicates. The groups are sorted by
+/// path so the reports are stable between runs
+fn get_equal_content_groups(
+    files_by_content_hash: &HashMap<(u64, u64), Vec<File>>,
+) -> Vec<Vec<String>> {
+    let mut groups: Vec<Vec<String>> = vec![];
+
+    for files in files_by_content_hash.values() {
+        if files.len() < 2 {
+            continue;
+        }
+
+        let mut candidates: Vec<(Vec<u8>, Vec<String>)> = vec![];
+
+        for file in files {
+            // files that can't be read are not reported as duplicates
+            let Ok(bytes) = file.get_bytes() else {
+                continue;
+            };
+
+            match candidates
+                .iter_mut()
+                .find(|(candidate_bytes, _)| *candidate_bytes == bytes)
+            {
+                Some((_, paths)) => paths.push(file.relative_path.clone()),
+                None => candidates.push((bytes, vec![file.relative_path.clone()])),
+            }
+        }
+
+        groups.extend(
+            candidates
+                .into_iter()
+                .map(|(_, paths)| paths)
+                .filter(|paths| paths.len() > 1),
+        );
+    }
+
+    for paths in &mut groups {
+        paths.sort();
+    }
+
+    groups.sort();
+
+    groups
+}
+
+pub fn get_duplicated_content_groups() -> Vec<Vec<String>> {
+    PROJECT_PATHS
+        .lock()
+        .unwrap()
+        .duplicated_content_groups
+        .clone()
+}
+
+pub fn check_content_is_unique(file: &File) -> Result<(), String> {
+    if file.size == 0 {
+        return Ok(());
+    }
+
+    if file.content_hash.is_none() {
+        return Err(String::from(
+            "content could not be checked, add the file extension to 'analyze_content_of_files_types' or lower the 'duplicate_content.min_size' option",
+        ));
+    }
+
+    let project_paths = PROJECT_PATHS.lock().unwrap();
+
+    let duplicated_paths: Vec<&str> = project_paths
+        .duplicated_content_groups
+        .iter()
+        .find(|paths| paths.contains(&file.relative_path))
+        .map(|paths| {
+            paths
+                .iter()
+                .filter(|path| **path != file.relative_path)
+                .map(|path| path.as_str())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if duplicated_paths.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "content should be unique, the same content was found in: {}",
+            duplicated_paths.join(", ")
+        ))
+    }
+}
+
+pub fn normalize_check_pattern(
     captures: &[Capture],
     check_pattern: &String,
+    acronyms: &Acronyms,
 ) -> Result<(String, Regex), String> {
-    let pattern =
-        replace_with_captures(check_pattern, captures, ContextVars::default());
+    let pattern = replace_with_captures(
+        check_pattern,
+        captures,
+        ContextVars::default(),
+        acronyms,
+    );
 
     let regex = if pattern.starts_with("regex:") {
         Regex::new(pattern.strip_prefix("regex:").unwrap_or(""))
@@ -238,11 +795,13 @@ pub fn check_path_pattern(
     path: &str,
     path_pattern: &String,
     condition_captures: &[Capture],
+    acronyms: &Acronyms,
 ) -> Result<(), String> {
     let pattern = replace_with_captures(
         path_pattern,
         condition_captures,
         ContextVars::default(),
+        acronyms,
     );
 
     let regex = get_regex_from_path_pattern(pattern.clone())?;
@@ -258,8 +817,10 @@ pub fn check_negated_path_pattern(
     path: &str,
     path_pattern: &String,
     condition_captures: &[Capture],
+    acronyms: &Acronyms,
 ) -> Result<(), String> {
-    let matches = check_path_pattern(path, path_pattern, condition_captures);
+    let matches =
+        check_path_pattern(path, path_pattern, condition_captures, acronyms);
 
     if matches.is_ok() {
         return Err(format!("should not match pattern '{}'", path_pattern));
@@ -268,107 +829,219 @@ pub fn check_negated_path_pattern(
     Ok(())
 }
 
+/// Returns the content of the lines in the scope and the byte offset where it
+/// starts in the file content
+fn get_scoped_content<'a>(
+    content: &'a str,
+    lines: &Option<LineScope>,
+) -> (&'a str, usize) {
+    let LineScope { start, end } = match lines {
+        Some(lines) => lines,
+        None => return (content, 0),
+    };
+
+    let line_start_offset = |line: usize| {
+        if line == 1 {
+            Some(0)
+        } else {
+            content
+                .match_indices('\n')
+                .nth(line - 2)
+                .map(|(index, _)| index + 1)
+        }
+    };
+
+    let start_offset = line_start_offset(*start).unwrap_or(content.len());
+    let end_offset = line_start_offset(end + 1)
+        .map(|offset| offset - 1)
+        .unwrap_or(content.len());
+
+    (&content[start_offset..end_offset], start_offset)
+}
+
+/// Formats the `line:column` location of a byte offset, both 1-based
+pub fn get_content_location(content: &str, offset: usize) -> String {
+    let before_offset = &content[..offset];
+
+    let line = before_offset.matches('\n').count() + 1;
+    let column = before_offset
+        .rsplit('\n')
+        .next()
+        .unwrap_or("")
+        .chars()
+        .count()
+        + 1;
+
+    format!("{}:{}", line, column)
+}
+
+fn get_line_scope_label(lines: &Option<LineScope>) -> String {
+    match lines {
+        None => String::from("in the file content"),
+        Some(LineScope { start, end }) if start == end => {
+            format!("on line {}", start)
+        }
+        Some(LineScope { start: 1, end }) => format!("in the first {} lines", end),
+        Some(LineScope { start, end }) => format!("in lines {}-{}", start, end),
+    }
+}
+
+/// Offsets of the pattern matches in the file content, sorted
+fn find_content_matches(
+    content: &str,
+    lines: &Option<LineScope>,
+    regexes: &[Regex],
+) -> Vec<usize> {
+    let (scoped_content, scope_offset) = get_scoped_content(content, lines);
+
+    let mut matches_offsets: Vec<usize> = regexes
+        .iter()
+        .flat_map(|regex| {
+            regex
+                .find_iter(scoped_content)
+                .map(|found| found.start() + scope_offset)
+        })
+        .collect();
+
+    matches_offsets.sort();
+
+    matches_offsets
+}
+
+/// Named captures of the first match of each pattern, the numbered captures
+/// are ignored so they don't override the `has_name` ones
+fn get_content_named_captures(
+    content: &str,
+    lines: &Option<LineScope>,
+    regexes: &[Regex],
+) -> Vec<Capture> {
+    let (scoped_content, _) = get_scoped_content(content, lines);
+
+    regexes
+        .iter()
+        .filter_map(|regex| {
+            regex.captures(scoped_content).map(|captures| {
+                regex
+                    .capture_names()
+                    .flatten()
+                    .filter_map(|name| {
+                        captures
+                            .name(name)
+                            .map(|value| Capture::new(name, value.as_str()))
+                    })
+                    .collect::<Vec<Capture>>()
+            })
+        })
+        .flatten()
+        .collect()
+}
+
+fn check_content_matches_count(
+    content: &str,
+    content_match: &ContentMatches,
+    matches_offsets: &[usize],
+) -> Result<(), String> {
+    let num_of_matches = matches_offsets.len();
+
+    if num_of_matches < content_match.at_least {
+        return Err(format!(
+            "content should match at least {} of the configured `content_matches` patterns but found {}",
+            content_match.at_least,
+            num_of_matches
+        ));
+    }
+
+    if let Some(at_most) = content_match.at_most {
+        if num_of_matches > at_most {
+            return Err(format!(
+                "content should match at most {} of the configured `content_matches` patterns but found {}, first extra match at {}",
+                at_most,
+                num_of_matches,
+                get_content_location(content, matches_offsets[at_most])
+            ));
+        }
+    }
+
+    Ok(())
+}
+
 pub fn check_content(
-    content: &Option<String>,
+    file: &File,
     content_matches: &Vec<ContentMatches>,
     condition_captures: &[Capture],
     some: bool,
-) -> Result<(), String> {
+    acronyms: &Acronyms,
+) -> Result<Vec<Capture>, String> {
     // unwrap or return error
-    let content = content.as_ref().ok_or(
+    let content = file.get_content()?.ok_or(
         "Empty content, check if the file type is added to `analyze_content_of_files_types` config",
     )?;
 
     let mut matched = false;
+    let mut content_captures: Vec<Capture> = vec![];
 
-    let not_found_msg =
-        "configured `content_matches` patterns not found in the file content"
-            .to_string();
+    let not_found_msg = |lines: &Option<LineScope>| {
+        format!(
+            "configured `content_matches` patterns not found {}",
+            get_line_scope_label(lines)
+        )
+    };
 
     for content_match in content_matches {
-        match content_match.matches.clone() {
-            crate::internal_config::Matches::Any(matches) => {
-                let mut num_of_matches = 0;
-
-                for pattern in matches {
-                    let (_, regex) =
-                        normalize_check_pattern(condition_captures, &pattern)?;
-
-                    let pattern_matches =
-                        regex.captures_iter(content.as_str()).count();
-
-                    num_of_matches += pattern_matches;
-                }
-
-                if num_of_matches == 0 {
-                    if !some {
-                        return Err(not_found_msg);
-                    }
-                } else {
-                    if num_of_matches < content_match.at_least {
-                        return Err(format!(
-                            "content should match at least {} of the configured `content_matches` patterns but found {}",
-                            content_match.at_least,
-                            num_of_matches
-                        ));
-                    }
-
-                    if let Some(at_most) = content_match.at_most {
-                        if num_of_matches > at_most {
-                            return Err(format!(
-                                "content should match at most {} of the configured `content_matches` patterns but found {}",
-                                at_most,
-                                num_of_matches
-                            ));
-                        }
-                    }
-
-                    matched = true;
-                }
-            }
-            crate::internal_config::Matches::All(matches) => {
-                for pattern in matches {
-                    let (_, regex) =
-                        normalize_check_pattern(condition_captures, &pattern)?;
-
-                    let pattern_matches =
-                        regex.captures_iter(content.as_str()).count();
-
-                    if pattern_matches == 0 {
-                        if !some {
-                            return Err(not_found_msg);
-                        }
-                    } else {
-                        if pattern_matches < content_match.at_least {
-                            return Err(format!(
-                                "content should match at least {} of the configured `content_matches` patterns but found {}",
-                                content_match.at_least,
-                                pattern_matches
-                            ));
-                        }
-
-                        if let Some(at_most) = content_match.at_most {
-                            if pattern_matches > at_most {
-                                return Err(format!(
-                                    "content should match at most {} of the configured `content_matches` patterns but found {}",
-                                    at_most,
-                                    pattern_matches
-                                ));
-                            }
-                        }
-
-                        matched = true;
-                    }
+        let regexes = |patterns: &Vec<String>| {
+            patterns
+                .iter()
+                .map(|pattern| {
+                    normalize_check_pattern(condition_captures, pattern, acronyms)
+                        .map(|(_, regex)| regex)
+                })
+                .collect::<Result<Vec<Regex>, String>>()
+        };
+
+        // `any` patterns are counted together, `all` patterns are counted
+        // one by one
+        let patterns_groups = match &content_match.matches {
+            crate::internal_config::Matches::Any(matches) => vec![regexes(matches)?],
+            crate::internal_config::Matches::All(matches) => regexes(matches)?
+                .into_iter()
+                .map(|regex| vec![regex])
+                .collect(),
+        };
+
+        for patterns_group in patterns_groups {
+            let matches_offsets = find_content_matches(
+                &content,
+                &content_match.lines,
+                &patterns_group,
+            );
+
+            if matches_offsets.is_empty() {
+                if !some {
+                    return Err(not_found_msg(&content_match.lines));
                 }
+            } else {
+                check_content_matches_count(
+                    &content,
+                    content_match,
+                    &matches_offsets,
+                )?;
+
+                content_captures.extend(get_content_named_captures(
+                    &content,
+                    &content_match.lines,
+                    &patterns_group,
+                ));
+
+                matched = true;
             }
         }
     }
 
     if some && !matched {
-        return Err(not_found_msg);
+        return Err(not_found_msg(&None));
     }
 
-    Ok(())
+    Ok(content_captures)
 }
 
 fn get_regex_from_path_pattern(pattern: String) -> Result<Regex, String> {
@@ -384,6 +1057,73 @@ fn get_regex_from_path_pattern(pattern: String) -> Result<Regex, String> {
     Regex::new(&normalize_pattern).map_err(|err| err.to_string())
 }
 
+/// Converts a path glob to a regex pattern, `*` matches a single path segment
+/// and can be used as capture, `**` matches any number of folders
+fn normalize_path_glob(pattern: &str) -> String {
+    pattern
+        .strip_prefix("./")
+        .unwrap_or(pattern)
+        .replace('.', "\\.")
+        .replace("**/", "\0")
+        .replace("/**", "\u{1}")
+        .replace("**", "\u{2}")
+        .replace('*', "([^/]+)")
+        .replace('\0', "(?:.*/)?")
+        .replace('\u{1}', "(?:/.*)?")
+        .replace('\u{2}', ".*")
+}
+
+fn get_regex_from_path_glob(pattern: &str) -> Result<Regex, String> {
+    if let Some(regex) = pattern.strip_prefix("regex:") {
+        return Regex::new(regex).map_err(|err| err.to_string());
+    }
+
+    let normalize_pattern = "^".to_string() + &normalize_path_glob(pattern) + "$";
+
+    Regex::new(&normalize_pattern).map_err(|err| err.to_string())
+}
+
+/// Matches the `./` prefixed path of a file or folder, the prefix is removed
+/// before matching
+pub fn root_path_pattern_match(
+    path: &str,
+    pattern: &str,
+) -> Result<Vec<Capture>, String> {
+    let regex = get_regex_from_path_glob(pattern)?;
+
+    match regex_match(&regex, path.strip_prefix("./").unwrap_or(path)) {
+        Ok(captures) => Ok(captures),
+        Err(_) => Err(format!("should match path pattern {}", pattern)),
+    }
+}
+
+/// Matches the pattern against any sequence of ancestor folders in the
+/// `./` prefixed `parent_path`, e.g. `features/*` matches `./src/features/auth`
+pub fn in_folder_pattern_match(
+    parent_path: &str,
+    pattern: &str,
+) -> Result<Vec<Capture>, String> {
+    let regex = if let Some(regex) = pattern.strip_prefix("regex:") {
+        Regex::new(regex)
+    } else {
+        Regex::new(&format!(
+            "^(?:.*/)?{}(?:/.*)?$",
+            normalize_path_glob(pattern)
+        ))
+    }
+    .map_err(|err| err.to_string())?;
+
+    let ancestors_path = parent_path
+        .strip_prefix('.')
+        .unwrap_or(parent_path)
+        .trim_start_matches('/');
+
+    match regex_match(&regex, ancestors_path) {
+        Ok(captures) if !ancestors_path.is_empty() => Ok(captures),
+        _ => Err(format!("should be in a folder matching {}", pattern)),
+    }
+}
+
 pub fn check_root_files_find_pattern(
     folder: &Folder,
     find_pattern: &RootFilesFindPattern,
@@ -429,6 +1169,7 @@ pub fn check_root_files_has_pattern(
     folder: &Folder,
     has_pattern: &String,
     condition_captures: &[Capture],
+    acronyms: &Acronyms,
 ) -> Result<String, String> {
     let pattern = replace_with_captures(
         has_pattern,
@@ -436,6 +1177,7 @@ pub fn check_root_files_has_pattern(
         ContextVars {
             folder_name: Some(folder.name.clone()),
         },
+        acronyms,
     );
 
     let regex = get_regex_from_path_pattern(pattern.clone())?;
@@ -458,9 +1200,14 @@ pub fn check_negated_root_files_has_pattern(
     folder: &Folder,
     has_pattern: &String,
     condition_captures: &[Capture],
+    acronyms: &Acronyms,
 ) -> Result<(), String> {
-    let matches =
-        check_root_files_has_pattern(folder, has_pattern, condition_captures);
+    let matches = check_root_files_has_pattern(
+        folder,
+        has_pattern,
+        condition_captures,
+        acronyms,
+    );
 
     if let Ok(pattern) = matches {
         return Err(format!(
@@ -473,21 +1220,43 @@ pub fn check_negated_root_files_has_pattern(
 }
 
 pub fn check_content_not_matches(
-    content: &Option<String>,
-    content_not_matches: &[String],
+    file: &File,
+    content_not_matches: &[ContentNotMatches],
     condition_captures: &[Capture],
+    acronyms: &Acronyms,
 ) -> Result<(), String> {
-    let content = content.as_ref().ok_or(
+    let content = file.get_content()?.ok_or(
         "Empty content, check if the file type is added to `analyze_content_of_files_types` config",
     )?;
 
-    for pattern in content_not_matches {
-        let (_, regex) = normalize_check_pattern(condition_captures, pattern)?;
+    for content_not_match in content_not_matches {
+        let (_, regex) = normalize_check_pattern(
+            condition_captures,
+            &content_not_match.pattern,
+            acronyms,
+        )?;
+
+        let matches_offsets =
+            find_content_matches(&content, &content_not_match.lines, &[regex]);
+
+        if !matches_offsets.is_empty() {
+            let locations: Vec<String> = matches_offsets
+                .iter()
+                .map(|offset| get_content_location(&content, *offset))
+                .collect();
+
+            let scope_label = match &content_not_match.lines {
+                Some(_) => {
+                    format!(" {}", get_line_scope_label(&content_not_match.lines))
+                }
+                None => String::new(),
+            };
 
-        if regex.is_match(content.as_str()) {
             return Err(format!(
-                "content should not match the configured `{}` pattern",
-                pattern
+                "content should not match the configured `{}` pattern{}, found at {}",
+                content_not_match.pattern,
+                scope_label,
+                locations.join(", ")
             ));
         }
     }
@@ -511,10 +1280,82 @@ pub fn check_folder_min_children(
     Ok(())
 }
 
+pub fn check_folder_max_children(
+    folder: &Folder,
+    max_children: usize,
+    children_kind: &str,
+    is_child: impl Fn(&FolderChild) -> bool,
+) -> Result<(), String> {
+    let num_of_children = folder
+        .children
+        .iter()
+        .filter(|child| is_child(child))
+        .count();
+
+    if num_of_children > max_children {
+        return Err(format!(
+            "should have at most {} {}, found {}",
+            max_children, children_kind, num_of_children
+        ));
+    }
+
+    Ok(())
+}
+
+fn get_folder_nesting_depth(folder: &Folder) -> usize {
+    folder
+        .children
+        .iter()
+        .filter_map(|child| match child {
+            FolderChild::Folder(sub_folder) => {
+                Some(get_folder_nesting_depth(sub_folder) + 1)
+            }
+            FolderChild::FileChild(_) => None,
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+pub fn check_folder_max_depth(
+    folder: &Folder,
+    max_depth: usize,
+) -> Result<(), String> {
+    let depth = get_folder_nesting_depth(folder);
+
+    if depth > max_depth {
+        return Err(format!(
+            "should have at most {} levels of nested folders, found {}",
+            max_depth, depth
+        ));
+    }
+
+    Ok(())
+}
+
+/// A folder is empty if it only has placeholder files or empty folders
+pub fn is_empty_folder(folder: &Folder, placeholders: &[String]) -> bool {
+    folder.children.iter().all(|child| match child {
+        FolderChild::FileChild(file) => placeholders.iter().any(|placeholder| {
+            path_pattern_match(&file.name_with_ext, placeholder).is_ok()
+        }),
+        FolderChild::Folder(sub_folder) => is_empty_folder(sub_folder, placeholders),
+    })
+}
+
+pub fn check_folder_is_not_empty(
+    folder: &Folder,
+    placeholders: &[String],
+) -> Result<(), String> {
+    if is_empty_folder(folder, placeholders) {
+        Err("folder is empty".to_string())
+    } else {
+        Ok(())
+    }
+}
+
 pub fn check_file_is_not_empty(file: &File) -> Result<(), String> {
     let is_empty = file
-        .content
-        .as_ref()
+        .get_content()?
         .is_none_or(|content| content.trim().is_empty());
 
     if is_empty {
@@ -524,6 +1365,73 @@ pub fn check_file_is_not_empty(file: &File) -> Result<(), String> {
     }
 }
 
+fn format_file_size(size: u64) -> String {
+    let units = [
+        ("gb", 1024 * 1024 * 1024),
+        ("mb", 1024 * 1024),
+        ("kb", 1024),
+    ];
+
+    for (unit, unit_size) in units {
+        if size >= unit_size {
+            let value = format!("{:.1}", size as f64 / unit_size as f64);
+
+            return format!("{}{}", value.trim_end_matches(".0"), unit);
+        }
+    }
+
+    format!("{}b", size)
+}
+
+pub fn check_file_max_size(file: &File, max_size: u64) -> Result<(), String> {
+    if file.size > max_size {
+        return Err(format!(
+            "should have a size of at most {}, found {}",
+            format_file_size(max_size),
+            format_file_size(file.size)
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn check_file_max_lines(file: &File, max_lines: usize) -> Result<(), String> {
+    if file.line_count > max_lines {
+        return Err(format!(
+            "should have at most {} lines, found {}",
+            max_lines, file.line_count
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn check_file_min_lines(file: &File, min_lines: usize) -> Result<(), String> {
+    if file.line_count < min_lines {
+        return Err(format!(
+            "should have at least {} lines, found {}",
+            min_lines, file.line_count
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn check_file_encoding(file: &File, encoding: &str) -> Result<(), String> {
+    if file.is_binary {
+        return Err(format!(
+            "should be encoded in {}, found binary content",
+            encoding
+        ));
+    }
+
+    if !file.is_utf8 {
+        return Err(format!("should be encoded in {}", encoding));
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -538,4 +1446,12 @@ mod tests {
 
         assert_eq!(regex.unwrap().as_str(), r"^test\.file(.+)$");
     }
+
+    #[test]
+    fn test_format_file_size() {
+        assert_eq!(format_file_size(300), "300b");
+        assert_eq!(format_file_size(1024), "1kb");
+        assert_eq!(format_file_size(1536), "1.5kb");
+        assert_eq!(format_file_size(200 * 1024 * 1024), "200mb");
+    }
 }
@@ -0,0 +1,401 @@
+use crate::{
+    check_folders::checks::{
+        get_project_data_file, normalize_check_pattern, replace_with_captures,
+        Capture, ContextVars,
+    },
+    internal_config::{Acronyms, DataMatches, JsonSchema},
+    load_folder_structure::File,
+};
+use jsonschema::Validator;
+use lazy_static::lazy_static;
+use serde_json::Value;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+lazy_static! {
+    static ref JSON_SCHEMA_VALIDATORS: Mutex<HashMap<String, Arc<Validator>>> =
+        Mutex::new(HashMap::new());
+}
+
+/// The schema files can change between runs in the same process, like in the
+/// test cases
+pub fn clear_json_schema_validators() {
+    JSON_SCHEMA_VALIDATORS.lock().unwrap().clear();
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum DataFormat {
+    Json,
+    Yaml,
+}
+
+impl DataFormat {
+    fn label(&self) -> &str {
+        match self {
+            DataFormat::Json => "JSON",
+            DataFormat::Yaml => "YAML",
+        }
+    }
+
+    fn from_extension(extension: &Option<String>) -> Self {
+        match extension.as_deref() {
+            Some("yaml" | "yml") => DataFormat::Yaml,
+            _ => DataFormat::Json,
+        }
+    }
+}
+
+/// Files that allow comments and trailing commas in their JSON, like the
+/// `tsconfig.json` files
+fn is_jsonc_file(file: &File) -> bool {
+    file.extension.as_deref() == Some("jsonc")
+        || ["tsconfig", "jsconfig"]
+            .iter()
+            .any(|name| file.basename.starts_with(name))
+}
+
+/// Removes the comments and trailing commas of JSONC content, the line breaks
+/// are kept so the parse errors still point to the right line
+fn remove_jsonc_syntax(content: &str) -> String {
+    let chars = content.chars().collect::<Vec<_>>();
+    let mut result = String::with_capacity(content.len());
+    let mut in_string = false;
+    let mut index = 0;
+
+    while index < chars.len() {
+        let current = chars[index];
+
+        if in_string {
+            result.push(current);
+
+            if current == '\\' {
+                result.extend(chars.get(index + 1));
+                index += 1;
+            } else if current == '"' {
+                in_string = false;
+            }
+
+            index += 1;
+            continue;
+        }
+
+        match (current, chars.get(index + 1)) {
+            ('/', Some('/')) => {
+                while index < chars.len() && chars[index] != '\n' {
+                    index += 1;
+                }
+            }
+            ('/', Some('*')) => {
+                index += 2;
+
+                while index < chars.len()
+                    && !(chars[index] == '*' && chars.get(index + 1) == Some(&'/'))
+                {
+                    if chars[index] == '\n' {
+                        result.push('\n');
+                    }
+
+                    index += 1;
+                }
+
+                index += 2;
+            }
+            _ => {
+                in_string = current == '"';
+                result.push(current);
+                index += 1;
+            }
+        }
+    }
+
+    remove_trailing_commas(&result)
+}
+
+fn remove_trailing_commas(content: &str) -> String {
+    let chars = content.chars().collect::<Vec<_>>();
+    let mut result = String::with_capacity(content.len());
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (index, current) in chars.iter().enumerate() {
+        if in_string {
+            in_string = escaped || *current != '"';
+            escaped = !escaped && *current == '\\';
+        } else if *current == '"' {
+            in_string = true;
+        } else if *current == ',' {
+            let next_char =
+                chars[index + 1..].iter().find(|char| !char.is_whitespace());
+
+            if matches!(next_char, Some('}' | ']')) {
+                continue;
+            }
+        }
+
+        result.push(*current);
+    }
+
+    result
+}
+
+fn parse_data_file(file: &File, format: DataFormat) -> Result<Value, String> {
+    let content = file.get_content()?.ok_or(
+        "Empty content, check if the file type is added to `analyze_content_of_files_types` config",
+    )?;
+
+    match format {
+        DataFormat::Json if is_jsonc_file(file) => {
+            serde_json::from_str(&remove_jsonc_syntax(&content))
+                .map_err(|err| err.to_string())
+        }
+        DataFormat::Json => {
+            serde_json::from_str(&content).map_err(|err| err.to_string())
+        }
+        DataFormat::Yaml => {
+            serde_norway::from_str(&content).map_err(|err| err.to_string())
+        }
+    }
+    .map_err(|err| {
+        format!("content could not be parsed as {}: {}", format.label(), err)
+    })
+}
+
+fn get_value_type(value: &Value) -> &str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(number) if number.is_i64() || number.is_u64() => "integer",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn value_is_of_type(value: &Value, type_is: &str) -> bool {
+    let value_type = get_value_type(value);
+
+    value_type == type_is || (type_is == "number" && value_type == "integer")
+}
+
+/// Replaces the captures in the strings of the expected value, including the
+/// nested ones
+fn replace_value_captures(
+    value: &Value,
+    captures: &[Capture],
+    folder_name: &str,
+    acronyms: &Acronyms,
+) -> Value {
+    match value {
+        Value::String(string) => Value::String(replace_with_captures(
+            string,
+            captures,
+            ContextVars {
+                folder_name: Some(folder_name.to_string()),
+            },
+            acronyms,
+        )),
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .map(|item| {
+                    replace_value_captures(item, captures, folder_name, acronyms)
+                })
+                .collect(),
+        ),
+        Value::Object(object) => Value::Object(
+            object
+                .iter()
+                .map(|(key, item)| {
+                    (
+                        key.clone(),
+                        replace_value_captures(
+                            item,
+                            captures,
+                            folder_name,
+                            acronyms,
+                        ),
+                    )
+                })
+                .collect(),
+        ),
+        _ => value.clone(),
+    }
+}
+
+fn get_file_folder_name(file: &File) -> &str {
+    file.relative_path
+        .rsplit('/')
+        .nth(1)
+        .filter(|folder_name| *folder_name != ".")
+        .unwrap_or("")
+}
+
+fn check_data_match(
+    data: &Value,
+    data_match: &DataMatches,
+    captures: &[Capture],
+    folder_name: &str,
+    acronyms: &Acronyms,
+) -> Result<(), String> {
+    let with_captures = |text: &String| {
+        replace_with_captures(
+            text,
+            captures,
+            ContextVars {
+                folder_name: Some(folder_name.to_string()),
+            },
+            acronyms,
+        )
+    };
+
+    let path = with_captures(&data_match.path);
+    let selected = data.pointer(&with_captures(&data_match.pointer));
+
+    let selected = match (selected, data_match.exists) {
+        (Some(selected), Some(false)) => {
+            return Err(format!(
+                "value at '{}' should not exist, found {}",
+                path, selected
+            ))
+        }
+        (None, Some(false)) => return Ok(()),
+        (Some(selected), _) => selected,
+        (None, _) => return Err(format!("value at '{}' not found", path)),
+    };
+
+    if let Some(type_is) = &data_match.type_is {
+        if !value_is_of_type(selected, type_is) {
+            return Err(format!(
+                "value at '{}' should be of type '{}', found '{}'",
+                path,
+                type_is,
+                get_value_type(selected)
+            ));
+        }
+    }
+
+    if let Some(equals) = &data_match.equals {
+        let expected =
+            replace_value_captures(equals, captures, folder_name, acronyms);
+
+        if *selected != expected {
+            return Err(format!(
+                "value at '{}' should be equal to {}, found {}",
+                path, expected, selected
+            ));
+        }
+    }
+
+    if let Some(matches) = &data_match.matches {
+        let (pattern, regex) =
+            normalize_check_pattern(&[], &with_captures(matches), acronyms)?;
+
+        let matched = match selected {
+            Value::String(string) => regex.is_match(string),
+            _ => false,
+        };
+
+        if !matched {
+            return Err(format!(
+                "value at '{}' should match pattern '{}', found {}",
+                path, pattern, selected
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn check_data_matches(
+    file: &File,
+    data_matches: &[DataMatches],
+    format: DataFormat,
+    captures: &[Capture],
+    acronyms: &Acronyms,
+) -> Result<(), String> {
+    let data = parse_data_file(file, format)?;
+    let folder_name = get_file_folder_name(file);
+
+    for data_match in data_matches {
+        check_data_match(&data, data_match, captures, folder_name, acronyms)?;
+    }
+
+    Ok(())
+}
+
+fn get_json_schema_validator(
+    json_schema: &JsonSchema,
+) -> Result<Arc<Validator>, String> {
+    let cache_key = match json_schema {
+        JsonSchema::Path(path) => path.clone(),
+        JsonSchema::Inline(schema) => schema.to_string(),
+    };
+
+    if let Some(validator) = JSON_SCHEMA_VALIDATORS.lock().unwrap().get(&cache_key) {
+        return Ok(validator.clone());
+    }
+
+    let schema = match json_schema {
+        JsonSchema::Path(path) => {
+            let schema_path = format!("./{}", path.trim_start_matches("./"));
+
+            let schema_file =
+                get_project_data_file(&schema_path).ok_or_else(|| {
+                    format!("JSON schema file '{}' not found", schema_path)
+                })?;
+
+            parse_data_file(
+                &schema_file,
+                DataFormat::from_extension(&schema_file.extension),
+            )
+            .map_err(|err| {
+                format!("invalid JSON schema '{}': {}", schema_path, err)
+            })?
+        }
+        JsonSchema::Inline(schema) => schema.clone(),
+    };
+
+    let validator = Arc::new(
+        jsonschema::validator_for(&schema)
+            .map_err(|err| format!("invalid JSON schema: {}", err))?,
+    );
+
+    JSON_SCHEMA_VALIDATORS
+        .lock()
+        .unwrap()
+        .insert(cache_key, validator.clone());
+
+    Ok(validator)
+}
+
+/// Validates JSON files, and YAML files by their extension, against the schema
+pub fn check_json_schema(
+    file: &File,
+    json_schema: &JsonSchema,
+) -> Result<(), String> {
+    let validator = get_json_schema_validator(json_schema)?;
+    let data = parse_data_file(file, DataFormat::from_extension(&file.extension))?;
+
+    let errors: Vec<String> = validator
+        .iter_errors(&data)
+        .map(|error| format!("   | {}: {}", error.instance_path(), error))
+        .collect();
+
+    if errors.is_empty() {
+        return Ok(());
+    }
+
+    let schema_label = match json_schema {
+        JsonSchema::Path(path) => format!("the JSON schema '{}'", path),
+        JsonSchema::Inline(_) => String::from("the configured JSON schema"),
+    };
+
+    Err(format!(
+        "should match {}:\n{}",
+        schema_label,
+        errors.join("\n")
+    ))
+}
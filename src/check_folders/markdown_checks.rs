@@ -0,0 +1,329 @@
+use crate::{
+    check_folders::checks::{
+        get_content_location, normalize_check_pattern, project_path_exists,
+        replace_with_captures, resolve_file_relative_path, Capture, ContextVars,
+    },
+    internal_config::{Acronyms, HeadingPattern},
+    load_folder_structure::File,
+};
+use pulldown_cmark::{Event, LinkType, Options, Parser, Tag, TagEnd};
+use regex::{escape, Regex};
+use serde_json::Value;
+
+struct MarkdownHeading {
+    level: u8,
+    text: String,
+}
+
+struct MarkdownLink {
+    dest_url: String,
+    offset: usize,
+}
+
+/// The parts of a markdown file used by the markdown assertions
+struct MarkdownDocument {
+    front_matter: Option<Value>,
+    headings: Vec<MarkdownHeading>,
+    links: Vec<MarkdownLink>,
+}
+
+fn parse_markdown(file: &File, content: &str) -> Result<MarkdownDocument, String> {
+    if !matches!(file.extension.as_deref(), Some("md" | "mdx")) {
+        return Err(String::from(
+            "markdown assertions can only be used in .md and .mdx files",
+        ));
+    }
+
+    let mut document = MarkdownDocument {
+        front_matter: None,
+        headings: vec![],
+        links: vec![],
+    };
+
+    let mut front_matter: Option<String> = None;
+    let mut heading: Option<MarkdownHeading> = None;
+
+    let parser =
+        Parser::new_ext(content, Options::ENABLE_YAML_STYLE_METADATA_BLOCKS);
+
+    for (event, range) in parser.into_offset_iter() {
+        match event {
+            Event::Start(Tag::MetadataBlock(_)) => {
+                front_matter = Some(String::new());
+            }
+            Event::End(TagEnd::MetadataBlock(_)) => {
+                let yaml = front_matter.take().unwrap_or_default();
+
+                document.front_matter = Some(
+                    serde_norway::from_str::<Option<Value>>(&yaml)
+                        .map_err(|err| {
+                            format!("front matter could not be parsed: {}", err)
+                        })?
+                        .unwrap_or(Value::Null),
+                );
+            }
+            Event::Start(Tag::Heading { level, .. }) => {
+                heading = Some(MarkdownHeading {
+                    level: level as u8,
+                    text: String::new(),
+                });
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some(heading) = heading.take() {
+                    document.headings.push(MarkdownHeading {
+                        level: heading.level,
+                        text: heading.text.trim().to_string(),
+                    });
+                }
+            }
+            Event::Start(Tag::Link {
+                link_type,
+                dest_url,
+                ..
+            })
+            | Event::Start(Tag::Image {
+                link_type,
+                dest_url,
+                ..
+            }) if !matches!(link_type, LinkType::Autolink | LinkType::Email) => {
+                document.links.push(MarkdownLink {
+                    dest_url: dest_url.to_string(),
+                    offset: range.start,
+                });
+            }
+            Event::Text(text) | Event::Code(text) => {
+                if let Some(front_matter) = &mut front_matter {
+                    front_matter.push_str(&text);
+                } else if let Some(heading) = &mut heading {
+                    heading.text.push_str(&text);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(document)
+}
+
+fn get_markdown_document(file: &File) -> Result<(MarkdownDocument, String), String> {
+    let content = file.get_content()?.ok_or(
+        "Empty content, check if the file type is added to `analyze_content_of_files_types` config",
+    )?;
+
+    Ok((parse_markdown(file, &content)?, content.to_string()))
+}
+
+pub fn check_front_matter_has(file: &File, keys: &[String]) -> Result<(), String> {
+    let (document, _) = get_markdown_document(file)?;
+
+    let front_matter = match document.front_matter {
+        Some(Value::Object(front_matter)) => front_matter,
+        Some(Value::Null) | None => {
+            return Err(String::from("should have a front matter"));
+        }
+        Some(_) => return Err(String::from("front matter should be a map")),
+    };
+
+    let missing_keys: Vec<&str> = keys
+        .iter()
+        .filter(|key| {
+            front_matter
+                .get(key.as_str())
+                .is_none_or(|value| value.is_null())
+        })
+        .map(|key| key.as_str())
+        .collect();
+
+    if missing_keys.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "front matter should have the keys: {}",
+            missing_keys.join(", ")
+        ))
+    }
+}
+
+/// The scalar front matter values can be used in the heading patterns, like
+/// `${front_matter.title}`
+fn get_front_matter_captures(front_matter: &Option<Value>) -> Vec<Capture> {
+    match front_matter {
+        Some(Value::Object(front_matter)) => front_matter
+            .iter()
+            .filter_map(|(key, value)| {
+                let value = match value {
+                    Value::String(value) => value.clone(),
+                    Value::Number(value) => value.to_string(),
+                    Value::Bool(value) => value.to_string(),
+                    _ => return None,
+                };
+
+                Some(Capture::new(&format!("front_matter.{}", key), &value))
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+fn get_heading_text_regex(
+    text_pattern: &str,
+    acronyms: &Acronyms,
+) -> Result<Regex, String> {
+    if text_pattern.starts_with("regex:") {
+        normalize_check_pattern(&[], &text_pattern.to_string(), acronyms)
+            .map(|(_, regex)| regex)
+    } else {
+        Regex::new(&format!("^{}$", escape(text_pattern))).map_err(|err| {
+            format!("Invalid heading pattern '{}': {}", text_pattern, err)
+        })
+    }
+}
+
+pub fn check_has_heading(
+    file: &File,
+    heading_patterns: &[HeadingPattern],
+    condition_captures: &[Capture],
+    acronyms: &Acronyms,
+) -> Result<(), String> {
+    let (document, _) = get_markdown_document(file)?;
+
+    let mut captures = condition_captures.to_vec();
+    captures.extend(get_front_matter_captures(&document.front_matter));
+
+    for heading_pattern in heading_patterns {
+        let text_pattern = heading_pattern.text.as_ref().map(|text| {
+            replace_with_captures(text, &captures, ContextVars::default(), acronyms)
+        });
+
+        let text_regex = text_pattern
+            .as_deref()
+            .map(|text_pattern| get_heading_text_regex(text_pattern, acronyms))
+            .transpose()?;
+
+        let num_of_headings = document
+            .headings
+            .iter()
+            .filter(|heading| {
+                heading_pattern
+                    .level
+                    .is_none_or(|level| level == heading.level)
+                    && text_regex
+                        .as_ref()
+                        .is_none_or(|regex| regex.is_match(&heading.text))
+            })
+            .count();
+
+        let heading_label = format!(
+            "{}heading{}",
+            heading_pattern
+                .level
+                .map(|level| format!("level {} ", level))
+                .unwrap_or_default(),
+            text_pattern
+                .map(|text| format!(" matching '{}'", text))
+                .unwrap_or_default()
+        );
+
+        if num_of_headings < heading_pattern.at_least {
+            return Err(format!(
+                "should have at least {} {}, found {}",
+                heading_pattern.at_least, heading_label, num_of_headings
+            ));
+        }
+
+        if let Some(at_most) = heading_pattern.at_most {
+            if num_of_headings > at_most {
+                return Err(format!(
+                    "should have at most {} {}, found {}",
+                    at_most, heading_label, num_of_headings
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn is_relative_link(dest_url: &str) -> bool {
+    !(dest_url.is_empty()
+        || dest_url.starts_with('#')
+        || dest_url.starts_with("//")
+        || dest_url.contains("://")
+        || dest_url.starts_with("mailto:")
+        || dest_url.starts_with("tel:")
+        || dest_url.starts_with("data:"))
+}
+
+fn decode_link_path(path: &str) -> String {
+    let mut decoded: Vec<u8> = vec![];
+    let bytes = path.as_bytes();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let encoded_byte = (bytes[index] == b'%')
+            .then(|| path.get(index + 1..index + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+        match encoded_byte {
+            Some(byte) => {
+                decoded.push(byte);
+                index += 3;
+            }
+            None => {
+                decoded.push(bytes[index]);
+                index += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Checks the relative links and images against the loaded project files and
+/// folders, the anchors and query strings are ignored
+pub fn check_relative_links_resolve(file: &File) -> Result<(), String> {
+    let (document, content) = get_markdown_document(file)?;
+
+    let broken_links: Vec<String> = document
+        .links
+        .iter()
+        .filter(|link| is_relative_link(&link.dest_url))
+        .filter(|link| {
+            let link_path =
+                link.dest_url.split(['#', '?']).next().unwrap_or_default();
+
+            if link_path.is_empty() {
+                return false;
+            }
+
+            match resolve_file_relative_path(file, &decode_link_path(link_path)) {
+                Some(resolved_path) => {
+                    let resolved_path = match resolved_path.as_str() {
+                        "./" => ".",
+                        path => path,
+                    };
+
+                    !project_path_exists(resolved_path)
+                }
+                None => true,
+            }
+        })
+        .map(|link| {
+            format!(
+                "   | {} at {}",
+                link.dest_url,
+                get_content_location(&content, link.offset)
+            )
+        })
+        .collect();
+
+    if broken_links.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "relative links should point to project files or folders, broken links:\n{}",
+            broken_links.join("\n")
+        ))
+    }
+}
@@ -11,13 +11,18 @@ use super::*;
 
 use crate::{
     analyze_ts_deps::{_setup_test, load_used_project_files_deps_info_from_cfg},
-    internal_config::{get_config, OneOfBlocks},
+    internal_config::{
+        get_config, set_severity_overrides, Acronyms, DotfilesPolicy, OneOfBlocks,
+        Severity, SymlinksPolicy,
+    },
     load_folder_structure,
+    load_folder_structure::{count_lines, get_flattened_files_structure},
     parse_config_file::{parse_config_string, ParseFrom},
     test_utils::TEST_MUTEX,
 };
 
 use pretty_assertions::assert_eq;
+use xxhash_rust::xxh3::xxh3_64;
 
 fn config_from_string(
     config_string: &String,
@@ -34,6 +39,7 @@ struct Project {
     structure: Folder,
     expected_errors: Option<Vec<String>>,
     expected_warnings: Option<Vec<String>>,
+    expected_infos: Option<Vec<String>>,
 }
 
 #[derive(Debug)]
@@ -74,6 +80,7 @@ struct ParsedProjectYaml {
     structure: ParsedFolder,
     expected_errors: Option<ExpectedErrors>,
     expected_warnings: Option<Vec<String>>,
+    expected_infos: Option<Vec<String>>,
 }
 
 fn convert_from_parsed_folder_to_project(
@@ -102,8 +109,18 @@ fn convert_from_parsed_folder_to_project(
                     basename,
                     name_with_ext: child_string.clone(),
                     content: Some(file_content.to_owned()),
+                    content_path: None,
+                    encoding: None,
+                    path: None,
                     extension: Some(extension),
                     relative_path: format!("{}/{}", path, child_string),
+                    is_symlink: false,
+                    size: file_content.len() as u64,
+                    line_count: count_lines(file_content),
+                    is_binary: file_content.contains('\0'),
+                    is_utf8: true,
+                    content_hash: (!file_content.is_empty())
+                        .then(|| xxh3_64(file_content.as_bytes())),
                 })
             }
             ParsedStructureChild::Folder(folder) => {
@@ -120,6 +137,7 @@ fn convert_from_parsed_folder_to_project(
     Folder {
         name: normalize_folder_config_name(&folder_name),
         children,
+        is_symlink: false,
     }
 }
 
@@ -145,6 +163,7 @@ fn parse_project_yaml(project_yaml: String) -> Result<Project, serde_norway::Err
             },
         },
         expected_warnings: parsed_project_yaml.expected_warnings,
+        expected_infos: parsed_project_yaml.expected_infos,
     })
 }
 
@@ -277,6 +296,50 @@ fn sort_vector(vector: &[String]) -> Vec<String> {
     new_vec
 }
 
+/// Checks the reported warnings or infos, `kind` is used in the test errors
+fn check_expected_problems(
+    kind: &str,
+    expected: &Option<Vec<String>>,
+    found: &[String],
+    test_case: &str,
+    test_errors: &mut Vec<String>,
+) {
+    let Some(expected) = expected else {
+        return;
+    };
+
+    if found.is_empty() {
+        test_errors.push(format!(
+            "{} Expected {} but no {} were found",
+            test_case, kind, kind
+        ));
+        return;
+    }
+
+    let collected = &found
+        .iter()
+        .map(|problem| problem.trim().to_string())
+        .collect::<Vec<String>>();
+
+    if !do_vecs_match(
+        &expected
+            .iter()
+            .map(|s| s.trim().to_string())
+            .collect::<Vec<String>>(),
+        collected,
+    ) {
+        test_errors.push(format!(
+            "{}\n\
+                Expected {}: {:#?}\n\
+                But got:          {:#?}",
+            test_case,
+            kind,
+            sort_vector(expected),
+            sort_vector(found)
+        ));
+    }
+}
+
 #[test]
 fn test_cases() {
     let files_content = get_test_cases("./src/test_cases");
@@ -428,13 +491,11 @@ fn test_cases() {
                                     i + 1
                                 );
 
-                                let Problems { errors, warnings } = match result {
-                                    Ok(_) => Problems {
-                                        errors: vec![],
-                                        warnings: vec![],
-                                    },
-                                    Err(problems) => problems,
-                                };
+                                let Problems {
+                                    errors,
+                                    warnings,
+                                    infos,
+                                } = result.err().unwrap_or_default();
 
                                 match &project.expected_errors {
                                     Some(expected_errors) => {
@@ -471,38 +532,21 @@ fn test_cases() {
                                     }
                                 }
 
-                                if let Some(expected_warnings) =
-                                    &project.expected_warnings
-                                {
-                                    if !warnings.is_empty() {
-                                        let collected = &warnings
-                                            .iter()
-                                            .map(|err| err.trim().to_string())
-                                            .collect::<Vec<String>>();
-
-                                        if !do_vecs_match(
-                                            &expected_warnings
-                                                .iter()
-                                                .map(|s| s.trim().to_string())
-                                                .collect::<Vec<String>>(),
-                                            collected,
-                                        ) {
-                                            test_errors.push(format!(
-                                                "{}\n\
-                                                    Expected warnings: {:#?}\n\
-                                                    But got:          {:#?}",
-                                                test_case,
-                                                sort_vector(expected_warnings),
-                                                sort_vector(&warnings)
-                                            ));
-                                        }
-                                    } else {
-                                        test_errors.push(format!(
-                                            "{} Expected warnings but no warnings were found",
-                                            test_case
-                                        ));
-                                    }
-                                }
+                                check_expected_problems(
+                                    "warnings",
+                                    &project.expected_warnings,
+                                    &warnings,
+                                    &test_case,
+                                    &mut test_errors,
+                                );
+
+                                check_expected_problems(
+                                    "infos",
+                                    &project.expected_infos,
+                                    &infos,
+                                    &test_case,
+                                    &mut test_errors,
+                                );
                             }
                         }
                         Err(error) => {
@@ -567,6 +611,60 @@ fn get_test_cases(dir: &str) -> Vec<(String, String)> {
         .collect::<Vec<(String, String)>>()
 }
 
+/// Replaces the on demand contents of a loaded structure with the file
+/// contents, the lines are counted from them because the files are only
+/// scanned when a rule needs it
+fn with_loaded_contents(folder: Folder) -> Folder {
+    Folder {
+        children: folder
+            .children
+            .into_iter()
+            .map(|child| match child {
+                FolderChild::FileChild(file) => {
+                    let content = file
+                        .get_content()
+                        .unwrap()
+                        .map(|content| content.to_string());
+
+                    FolderChild::FileChild(File {
+                        line_count: content.as_deref().map_or(0, count_lines),
+                        content,
+                        content_path: None,
+                        encoding: None,
+                        path: None,
+                        ..file
+                    })
+                }
+                FolderChild::Folder(folder) => {
+                    FolderChild::Folder(with_loaded_contents(folder))
+                }
+            })
+            .collect(),
+        ..folder
+    }
+}
+
+/// The test structures hash all the contents, the loaded files are only
+/// hashed when `duplicate_content` is configured
+fn without_content_hashes(folder: Folder) -> Folder {
+    Folder {
+        children: folder
+            .children
+            .into_iter()
+            .map(|child| match child {
+                FolderChild::FileChild(file) => FolderChild::FileChild(File {
+                    content_hash: None,
+                    ..file
+                }),
+                FolderChild::Folder(folder) => {
+                    FolderChild::Folder(without_content_hashes(folder))
+                }
+            })
+            .collect(),
+        ..folder
+    }
+}
+
 #[test]
 fn test_case_folder_structure_is_equal_to_loaded_structure() {
     let parsed_structure = parse_project_yaml(
@@ -604,7 +702,8 @@ fn test_case_folder_structure_is_equal_to_loaded_structure() {
                 not_touch: false,
                 allow_unexpected_files: false,
                 allow_unexpected_folders: false,
-                is_warning: false,
+                id: None,
+                severity: Severity::Error,
             }],
             one_of_blocks: OneOfBlocks::default(),
             optional: false,
@@ -612,21 +711,473 @@ fn test_case_folder_structure_is_equal_to_loaded_structure() {
             unexpected_files_error_msg: None,
             unexpected_folders_error_msg: None,
             append_error_msg: None,
+            case_collisions: None,
             unexpected_error_msg: None,
         },
+        use_gitignore: false,
+        dotfiles: DotfilesPolicy::default(),
+        symlinks: SymlinksPolicy::default(),
+        encoding: None,
+        report_empty_folders: None,
+        duplicate_content: None,
         ts_config: None,
         error_msg_vars: None,
+        acronyms: Acronyms::default(),
+        severity_overrides: HashMap::new(),
     };
 
     let root = PathBuf::from("./src/fixtures/analyze_file_contents");
 
-    let loaded = load_folder_structure(&root, &config, &root, true).unwrap();
+    let loaded = with_loaded_contents(
+        load_folder_structure(&root, &config, &root, true).unwrap(),
+    );
 
     assert_eq!(
         loaded,
-        Folder {
+        without_content_hashes(Folder {
             name: "analyze_file_contents".to_string(),
             children: parsed_structure.structure.children,
-        }
+            is_symlink: false,
+        })
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn symlinks_policies() {
+    let root = std::env::temp_dir().join("palinter_symlinks_policies");
+    let outside = std::env::temp_dir().join("palinter_symlinks_policies_outside");
+
+    let _ = std::fs::remove_dir_all(&root);
+    let _ = std::fs::remove_dir_all(&outside);
+
+    std::fs::create_dir_all(&outside).unwrap();
+    std::fs::write(outside.join("external.ts"), "").unwrap();
+
+    std::fs::create_dir_all(root.join("src/real")).unwrap();
+    std::fs::write(root.join("src/real/index.ts"), "").unwrap();
+    std::os::unix::fs::symlink(root.join("src/real"), root.join("src/linked"))
+        .unwrap();
+    std::os::unix::fs::symlink(
+        root.join("src/real/index.ts"),
+        root.join("src/link.ts"),
+    )
+    .unwrap();
+    std::os::unix::fs::symlink(root.join("src"), root.join("src/real/loop"))
+        .unwrap();
+    std::os::unix::fs::symlink(
+        root.join("src/nowhere.ts"),
+        root.join("src/broken.ts"),
+    )
+    .unwrap();
+    std::os::unix::fs::symlink(&outside, root.join("src/external")).unwrap();
+    std::os::unix::fs::symlink(
+        outside.join("external.ts"),
+        root.join("src/external.ts"),
+    )
+    .unwrap();
+
+    let check = |config_yaml: &str| {
+        let config =
+            config_from_string(&config_yaml.to_string(), ParseFrom::Yaml).unwrap();
+
+        let folder = load_folder_structure(&root, &config, &root, true).unwrap();
+
+        colored::control::set_override(false);
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let mut errors = check_root_folder(&config, &folder, false, false)
+            .err()
+            .map(|problems| problems.errors)
+            .unwrap_or_default();
+
+        errors.sort();
+
+        errors
+    };
+
+    let rules = |policy: &str| {
+        format!(
+            "
+        symlinks: {}
+        ./:
+          /src:
+            rules:
+              - if_file:
+                  is_symlink: true
+                expect:
+                  name_is: allowed-link.ts
+              - if_file:
+                  not:
+                    is_symlink: true
+                expect: any
+              - if_folder:
+                  is_symlink: true
+                expect:
+                  name_is: shared
+                allow_unexpected: true
+              - if_folder:
+                  not:
+                    is_symlink: true
+                expect: any
+                allow_unexpected: true
+            ",
+            policy
+        )
+    };
+
+    // the links to targets outside the root are not followed
+    assert_eq!(
+        check(&rules("follow")),
+        vec![
+            "File ./src/link.ts:\n • should match pattern 'allowed-link.ts'",
+            "Folder ./src/linked:\n • should match pattern 'shared'",
+        ]
+    );
+
+    assert_eq!(check(&rules("skip")), Vec::<String>::new());
+
+    assert_eq!(
+        check(&rules("report")),
+        vec![
+            "Symlink /external is not allowed in folder ./src",
+            "Symlink /linked is not allowed in folder ./src",
+            "Symlink /loop is not allowed in folder ./src/real",
+            "Symlink broken.ts is not allowed in folder ./src",
+            "Symlink external.ts is not allowed in folder ./src",
+            "Symlink link.ts is not allowed in folder ./src",
+        ]
+    );
+
+    std::fs::remove_dir_all(&root).unwrap();
+    std::fs::remove_dir_all(&outside).unwrap();
+}
+
+#[test]
+fn binary_and_non_utf8_files() {
+    let root = std::env::temp_dir().join("palinter_binary_and_non_utf8_files");
+
+    let _ = std::fs::remove_dir_all(&root);
+
+    std::fs::create_dir_all(root.join("src")).unwrap();
+    // "café" encoded in latin1
+    std::fs::write(root.join("src/legacy.txt"), b"caf\xe9\n").unwrap();
+    std::fs::write(root.join("src/image.txt"), b"\x89PNG\r\n\x1a\n\0\0").unwrap();
+    std::fs::write(root.join("src/valid.txt"), "café\n").unwrap();
+
+    let check = |encoding: &str| {
+        let config = config_from_string(
+            &format!(
+                "
+                analyze_content_of_files_types: [txt]
+                {}
+                ./:
+                  /src:
+                    rules:
+                      - if_file:
+                          is_binary: true
+                        expect:
+                          name_is: image.txt
+                      - if_file: any
+                        expect:
+                          content_matches: 'café'
+                          encoding_is: utf-8
+                ",
+                encoding
+            ),
+            ParseFrom::Yaml,
+        )
+        .unwrap();
+
+        colored::control::set_override(false);
+        // the configured encoding is global
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let folder = load_folder_structure(&root, &config, &root, true).unwrap();
+
+        let mut errors = check_root_folder(&config, &folder, false, false)
+            .err()
+            .map(|problems| problems.errors)
+            .unwrap_or_default();
+
+        errors.sort();
+
+        errors
+    };
+
+    assert_eq!(
+        check(""),
+        vec![
+            "File ./src/image.txt:\n • file is binary, its content can't be analyzed",
+            "File ./src/image.txt:\n • should be encoded in utf-8, found binary content",
+            "File ./src/legacy.txt:\n • configured `content_matches` patterns not found in the file content",
+            "File ./src/legacy.txt:\n • should be encoded in utf-8",
+        ]
+    );
+
+    assert_eq!(
+        check("encoding: latin1"),
+        vec![
+            "File ./src/image.txt:\n • file is binary, its content can't be analyzed",
+            "File ./src/image.txt:\n • should be encoded in utf-8, found binary content",
+            "File ./src/legacy.txt:\n • should be encoded in utf-8",
+        ]
+    );
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn files_are_scanned_only_when_needed() {
+    let root =
+        std::env::temp_dir().join("palinter_files_are_scanned_only_when_needed");
+
+    let _ = std::fs::remove_dir_all(&root);
+
+    std::fs::create_dir_all(root.join("src")).unwrap();
+    std::fs::write(root.join("src/lines.txt"), "a\nb\nc\n").unwrap();
+    std::fs::write(root.join("src/image.txt"), b"\x89PNG\r\n\x1a\n\0\0").unwrap();
+
+    let load = |expect: &str| {
+        let config = config_from_string(
+            &format!(
+                "
+                analyze_content_of_files_types: [txt]
+                ./:
+                  /src:
+                    rules:
+                      - if_file:
+                          has_name: lines.txt
+                        expect:
+                          {}
+                      - if_file:
+                          has_name: image.txt
+                        expect:
+                          content_matches: PNG
+                ",
+                expect
+            ),
+            ParseFrom::Yaml,
+        )
+        .unwrap();
+
+        let folder = load_folder_structure(&root, &config, &root, true).unwrap();
+
+        colored::control::set_override(false);
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let mut errors = check_root_folder(&config, &folder, false, false)
+            .err()
+            .map(|problems| problems.errors)
+            .unwrap_or_default();
+
+        errors.sort();
+
+        (get_flattened_files_structure(&folder), errors)
+    };
+
+    // binary files are still detected when the content is read
+    let (files, errors) = load("is_not_empty: true");
+
+    assert_eq!(files["./src/lines.txt"].line_count, 0);
+    assert_eq!(
+        errors,
+        vec!["File ./src/image.txt:\n • file is binary, its content can't be analyzed"]
+    );
+
+    let (files, errors) = load("max_lines: 2");
+
+    assert_eq!(files["./src/lines.txt"].line_count, 3);
+    assert_eq!(
+        errors,
+        vec![
+            "File ./src/image.txt:\n • file is binary, its content can't be analyzed",
+            "File ./src/lines.txt:\n • should have at most 2 lines, found 3",
+        ]
+    );
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn severity_overrides() {
+    let structure = parse_project_yaml(
+        r#"
+            structure:
+              wrong-name.tsx: ''
+              /styles:
+                wrongStyles.css: ''
+              /scripts:
+                wrong-script.ts: ''
+        "#
+        .to_string(),
+    )
+    .unwrap()
+    .structure;
+
+    let get_config = || {
+        config_from_string(
+            &r#"
+            blocks:
+              kebab_case_styles:
+                if_file:
+                  has_name: '*.css'
+                expect:
+                  name_case_is: kebab-case
+
+            ./:
+              rules:
+                - if_file: any
+                  id: camel-case-files
+                  expect:
+                    - name_case_is: camelCase
+                    - extension_is: ts
+                      severity: info
+                  severity: warn
+                  non_recursive: true
+
+              /styles:
+                rules:
+                  - 'kebab_case_styles'
+
+              /scripts:
+                rules:
+                  - if_file:
+                      has_name: '*.ts'
+                    id: scripts-case
+                    expect_one_of:
+                      - name_case_is: camelCase
+                      - name_case_is: PascalCase
+                    error_msg: should be named in camelCase or PascalCase
+            "#
+            .to_string(),
+            ParseFrom::Yaml,
+        )
+        .unwrap()
+    };
+
+    let check = |overrides: &[(&str, Severity)]| {
+        let mut config = get_config();
+
+        set_severity_overrides(
+            &mut config,
+            &overrides
+                .iter()
+                .map(|(rule_id, severity)| (rule_id.to_string(), *severity))
+                .collect::<Vec<_>>(),
+        )?;
+
+        colored::control::set_override(false);
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let problems = check_root_folder(&config, &structure, false, true)
+            .err()
+            .unwrap_or_default();
+
+        Ok::<_, String>((problems.errors, problems.warnings, problems.infos))
+    };
+
+    assert_eq!(
+        check(&[]).unwrap(),
+        (
+            vec![
+                "File ./scripts/wrong-script.ts:\n • should be named in camelCase or PascalCase"
+                    .to_string(),
+                "File ./styles/wrongStyles.css:\n • should be named in kebab-case"
+                    .to_string(),
+            ],
+            vec!["File ./wrong-name.tsx:\n • should be named in camelCase"
+                .to_string()],
+            vec!["File ./wrong-name.tsx:\n • should have extension 'ts'".to_string()],
+        )
+    );
+
+    // the overrides take precedence over the expect severities, the rules from
+    // blocks use the block name as id
+    assert_eq!(
+        check(&[
+            ("camel-case-files", Severity::Error),
+            ("kebab_case_styles", Severity::Off),
+            ("scripts-case", Severity::Info),
+        ])
+        .unwrap(),
+        (
+            vec![
+                "File ./wrong-name.tsx:\n • should be named in camelCase"
+                    .to_string(),
+                "File ./wrong-name.tsx:\n • should have extension 'ts'".to_string(),
+            ],
+            vec![],
+            vec![
+                "File ./scripts/wrong-script.ts:\n • should be named in camelCase or PascalCase"
+                    .to_string()
+            ],
+        )
+    );
+
+    assert_eq!(
+        check(&[("unknown-rule", Severity::Warn)]).unwrap_err(),
+        "Rule id 'unknown-rule' used in '--severity' not found in the config"
+    );
+}
+
+#[test]
+fn duplicate_content_requires_equal_bytes() {
+    let _guard = TEST_MUTEX.lock().unwrap();
+
+    // all the files have the same hash to simulate hash collisions
+    let file = |name: &str, content: &str| {
+        FolderChild::FileChild(File {
+            basename: name.to_string(),
+            name_with_ext: format!("{}.txt", name),
+            content: Some(content.to_string()),
+            extension: Some("txt".to_string()),
+            relative_path: format!("./{}.txt", name),
+            size: content.len() as u64,
+            line_count: count_lines(content),
+            is_utf8: true,
+            content_hash: Some(1),
+            ..Default::default()
+        })
+    };
+
+    let folder = Folder {
+        name: ".".to_string(),
+        children: vec![
+            file("a", "same"),
+            file("b", "same"),
+            file("collision", "diff"),
+            file("longer", "same!"),
+            file("c", "diff"),
+        ],
+        is_symlink: false,
+    };
+
+    set_project_paths(&folder);
+
+    assert_eq!(
+        get_duplicated_content_groups(),
+        vec![
+            vec!["./a.txt".to_string(), "./b.txt".to_string()],
+            vec!["./c.txt".to_string(), "./collision.txt".to_string()],
+        ]
+    );
+
+    let FolderChild::FileChild(longer) = &folder.children[3] else {
+        unreachable!()
+    };
+
+    assert_eq!(check_content_is_unique(longer), Ok(()));
+
+    let FolderChild::FileChild(a) = &folder.children[0] else {
+        unreachable!()
+    };
+
+    assert_eq!(
+        check_content_is_unique(a),
+        Err(
+            "content should be unique, the same content was found in: ./b.txt"
+                .to_string()
+        )
     );
 }
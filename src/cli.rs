@@ -1,7 +1,9 @@
-use std::path::PathBuf;
+use std::{path::PathBuf, str::FromStr};
 
 use clap::{builder, Arg, ArgAction, ArgMatches, Command};
 
+use crate::internal_config::Severity;
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ThreadsArg {
     Count(usize),
@@ -67,6 +69,20 @@ fn parse_threads_arg(value: &str) -> Result<ThreadsArg, String> {
     Ok(ThreadsArg::Count(threads))
 }
 
+fn parse_severity_override(value: &str) -> Result<(String, Severity), String> {
+    let (rule_id, severity) = value
+        .split_once('=')
+        .filter(|(rule_id, _)| !rule_id.is_empty())
+        .ok_or_else(|| {
+            format!(
+                "Invalid '--severity' value '{}'. Use the format 'rule_id=level'",
+                value
+            )
+        })?;
+
+    Ok((rule_id.to_string(), Severity::from_str(severity)?))
+}
+
 #[derive(Debug, PartialEq)]
 pub struct CliArgs {
     pub command: CliCommand,
@@ -91,6 +107,8 @@ pub enum CliCommand {
         root: PathBuf,
         cfg_path: PathBuf,
         allow_warnings: bool,
+        severity_overrides: Vec<(String, Severity)>,
+        max_warnings: Option<usize>,
     },
 }
 
@@ -116,7 +134,20 @@ fn get_clap_command() -> Command {
             Arg::new("allow-warnings")
                 .long("allow-warnings")
                 .action(ArgAction::SetTrue)
-                .help("Show rules with `is_warning` set to true as warnings instead of errors")
+                .help("Show rules with the `warn` severity as warnings instead of errors")
+        )
+        .arg(
+            Arg::new("severity")
+                .long("severity")
+                .action(ArgAction::Append)
+                .help("Override the severity of the rules with an id, e.g. --severity rule_id=warn")
+                .value_parser(parse_severity_override),
+        )
+        .arg(
+            Arg::new("max-warnings")
+                .long("max-warnings")
+                .help("Fail if more than n warnings are found, implies --allow-warnings")
+                .value_parser(builder::RangedU64ValueParser::<usize>::new().range(0..)),
         )
         .arg(
             Arg::new("threads")
@@ -216,6 +247,11 @@ fn get_cli_cmd_from_matches(matches: &ArgMatches) -> CliCommand {
             root: matches.get_one::<PathBuf>("root").unwrap().clone(),
             cfg_path: matches.get_one::<PathBuf>("config").unwrap().clone(),
             allow_warnings: matches.get_flag("allow-warnings"),
+            severity_overrides: matches
+                .get_many::<(String, Severity)>("severity")
+                .map(|overrides| overrides.cloned().collect())
+                .unwrap_or_default(),
+            max_warnings: matches.get_one::<usize>("max-warnings").copied(),
         },
     }
 }
@@ -252,6 +288,8 @@ mod tests {
                 root: PathBuf::from("."),
                 cfg_path: PathBuf::from("palinter.yaml"),
                 allow_warnings: false,
+                severity_overrides: vec![],
+                max_warnings: None,
             }
         );
 
@@ -264,6 +302,8 @@ mod tests {
                 root: PathBuf::from("src/project"),
                 cfg_path: PathBuf::from("palinter.yaml"),
                 allow_warnings: false,
+                severity_overrides: vec![],
+                max_warnings: None,
             }
         );
 
@@ -281,10 +321,54 @@ mod tests {
                 root: PathBuf::from("src/project"),
                 cfg_path: PathBuf::from("custom-config.yaml"),
                 allow_warnings: true,
+                severity_overrides: vec![],
+                max_warnings: None,
+            }
+        );
+
+        // Test with severity overrides and max warnings
+        assert_eq!(
+            get_cli_cmd_from_shell_string(vec![
+                "palinter",
+                "--severity",
+                "new-rule=warn",
+                "--severity",
+                "legacy-rule=off",
+                "--max-warnings",
+                "10",
+            ]),
+            CliCommand::Lint {
+                root: PathBuf::from("."),
+                cfg_path: PathBuf::from("palinter.yaml"),
+                allow_warnings: false,
+                severity_overrides: vec![
+                    ("new-rule".to_string(), Severity::Warn),
+                    ("legacy-rule".to_string(), Severity::Off),
+                ],
+                max_warnings: Some(10),
             }
         );
     }
 
+    #[test]
+    fn test_invalid_severity_override() {
+        let cli = get_clap_command();
+
+        assert!(cli
+            .clone()
+            .try_get_matches_from(vec!["palinter", "--severity", "new-rule"])
+            .is_err());
+
+        assert!(cli
+            .clone()
+            .try_get_matches_from(vec!["palinter", "--severity", "=warn"])
+            .is_err());
+
+        assert!(cli
+            .try_get_matches_from(vec!["palinter", "--severity", "new-rule=fatal"])
+            .is_err());
+    }
+
     #[test]
     fn test_circular_deps_command_variations() {
         // Test with default config and root
@@ -0,0 +1,176 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Arc,
+};
+
+#[derive(Debug)]
+struct CacheEntry {
+    content: Arc<str>,
+    /// Generation of the last use, the older records of the entry in the
+    /// usage queue are stale
+    generation: u64,
+}
+
+/// Keeps the most recently used contents until their total size reaches
+/// `max_size` bytes, the least recently used ones are dropped first.
+///
+/// Each use pushes a record to the usage queue instead of moving the entry,
+/// the stale records are skipped when evicting so all the operations are O(1)
+/// amortized
+#[derive(Debug)]
+pub struct ContentCache {
+    entries: HashMap<Arc<str>, CacheEntry>,
+    usage_queue: VecDeque<(u64, Arc<str>)>,
+    next_generation: u64,
+    size: usize,
+    max_size: usize,
+}
+
+impl ContentCache {
+    pub fn new(max_size: usize) -> Self {
+        ContentCache {
+            entries: HashMap::new(),
+            usage_queue: VecDeque::new(),
+            next_generation: 0,
+            size: 0,
+            max_size,
+        }
+    }
+
+    pub fn get(&mut self, key: &str) -> Option<Arc<str>> {
+        let (key, entry) = self.entries.get_key_value(key)?;
+        let (key, content) = (key.clone(), entry.content.clone());
+
+        self.mark_as_used(key);
+
+        Some(content)
+    }
+
+    pub fn insert(&mut self, key: String, content: Arc<str>) {
+        if let Some(previous) = self.entries.remove(key.as_str()) {
+            self.size -= previous.content.len();
+        }
+
+        // contents larger than the cache are not kept
+        if content.len() > self.max_size {
+            return;
+        }
+
+        while self.size + content.len() > self.max_size {
+            if !self.evict_least_recently_used() {
+                break;
+            }
+        }
+
+        let key: Arc<str> = Arc::from(key);
+
+        self.size += content.len();
+        self.entries.insert(
+            key.clone(),
+            CacheEntry {
+                content,
+                generation: 0,
+            },
+        );
+        self.mark_as_used(key);
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.usage_queue.clear();
+        self.size = 0;
+    }
+
+    fn mark_as_used(&mut self, key: Arc<str>) {
+        let generation = self.next_generation;
+        self.next_generation += 1;
+
+        if let Some(entry) = self.entries.get_mut(&key) {
+            entry.generation = generation;
+        }
+
+        self.usage_queue.push_back((generation, key));
+
+        // the stale records are dropped once they outnumber the entries, which
+        // keeps the queue bounded without a cost per use
+        if self.usage_queue.len() > 2 * self.entries.len() + 16 {
+            let entries = &self.entries;
+
+            self.usage_queue.retain(|(generation, key)| {
+                is_current_use(entries, key, *generation)
+            });
+        }
+    }
+
+    /// Returns false if there are no entries left to evict
+    fn evict_least_recently_used(&mut self) -> bool {
+        while let Some((generation, key)) = self.usage_queue.pop_front() {
+            if is_current_use(&self.entries, &key, generation) {
+                if let Some(removed) = self.entries.remove(&key) {
+                    self.size -= removed.content.len();
+                }
+
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+fn is_current_use(
+    entries: &HashMap<Arc<str>, CacheEntry>,
+    key: &str,
+    generation: u64,
+) -> bool {
+    entries
+        .get(key)
+        .is_some_and(|entry| entry.generation == generation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drops_least_recently_used_contents() {
+        let mut cache = ContentCache::new(10);
+
+        cache.insert("a".to_string(), Arc::from("aaaa"));
+        cache.insert("b".to_string(), Arc::from("bbbb"));
+
+        assert_eq!(cache.get("a").as_deref(), Some("aaaa"));
+
+        cache.insert("c".to_string(), Arc::from("cccc"));
+
+        assert_eq!(cache.get("a").as_deref(), Some("aaaa"));
+        assert_eq!(cache.get("b"), None);
+        assert_eq!(cache.get("c").as_deref(), Some("cccc"));
+
+        cache.insert("d".to_string(), Arc::from("ddddddddddd"));
+
+        assert_eq!(cache.get("d"), None);
+        assert_eq!(cache.get("c").as_deref(), Some("cccc"));
+    }
+
+    #[test]
+    fn stale_usage_records_are_dropped() {
+        let mut cache = ContentCache::new(10);
+
+        cache.insert("a".to_string(), Arc::from("aaaa"));
+        cache.insert("b".to_string(), Arc::from("bbbb"));
+
+        for _ in 0..1000 {
+            cache.get("b");
+            cache.get("a");
+        }
+
+        assert!(cache.usage_queue.len() <= 2 * cache.entries.len() + 16);
+
+        cache.insert("c".to_string(), Arc::from("cccc"));
+
+        assert_eq!(cache.get("b"), None);
+        assert_eq!(cache.get("a").as_deref(), Some("aaaa"));
+        assert_eq!(cache.get("c").as_deref(), Some("cccc"));
+    }
+}
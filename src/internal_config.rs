@@ -1,14 +1,23 @@
+use encoding_rs::Encoding;
 use indexmap::IndexMap;
-use std::collections::{BTreeMap, HashMap, HashSet};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    str::FromStr,
+};
 
+use regex::{escape, Regex};
 use serde_norway::Value;
 
 use crate::{
     parse_config_file::{
         CorrectParsedFolderConfig, ParsedAnyNoneOrConditions, ParsedBlocks,
-        ParsedConfig, ParsedFileConditions, ParsedFileContentMatches,
-        ParsedFileContentMatchesItem, ParsedFileExpect, ParsedFolderConfig,
-        ParsedFolderExpect, ParsedMatchImport, ParsedRule, SingleOrMultiple,
+        ParsedConfig, ParsedContentNotMatchesItem, ParsedDataMatches,
+        ParsedDuplicateContent, ParsedEmptyFolderCheck, ParsedFileConditions,
+        ParsedFileContentMatches, ParsedFileContentMatchesItem, ParsedFileExpect,
+        ParsedFileSize, ParsedFolderConditions, ParsedFolderConfig,
+        ParsedFolderExpect, ParsedHeadingPattern, ParsedJsonSchema,
+        ParsedMatchImport, ParsedRule, ParsedSiblingPattern, ParsedUniqueNameScope,
+        SingleOrMultiple,
     },
     utils::clone_extend_vec,
 };
@@ -33,16 +42,53 @@ pub enum NameCase {
     Kebab,
     Pascal,
     Constant,
+    Dot,
+    Train,
+    Title,
+    Lower,
+    Flat,
+    Custom { name: String, regex: String },
+}
+
+impl NameCase {
+    pub fn name(&self) -> &str {
+        match self {
+            NameCase::Camel => "camelCase",
+            NameCase::Snake => "snake_case",
+            NameCase::Kebab => "kebab-case",
+            NameCase::Pascal => "PascalCase",
+            NameCase::Constant => "CONSTANT_CASE",
+            NameCase::Dot => "dot.case",
+            NameCase::Train => "Train-Case",
+            NameCase::Title => "Title Case",
+            NameCase::Lower => "lowercase",
+            NameCase::Flat => "flatcase",
+            NameCase::Custom { name, .. } => name,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct FileConditions {
     pub has_extension: Option<Vec<String>>,
+    pub has_suffix: Option<Vec<String>>,
     pub has_name: Option<String>,
     pub not_has_name: Option<String>,
+    /// Pattern matched against the path relative to the root folder
+    pub has_path: Option<String>,
+    pub not_has_path: Option<String>,
+    /// Pattern matched against the ancestor folders, at any depth
+    pub in_folder: Option<String>,
+    pub not_in_folder: Option<String>,
     pub has_content: Option<Vec<ContentMatches>>,
     pub has_any_content: Option<Vec<ContentMatches>>,
-    pub not_has_content: Option<Vec<String>>,
+    pub not_has_content: Option<Vec<ContentNotMatches>>,
+    pub is_symlink: bool,
+    pub is_larger_than: Option<u64>,
+    pub is_binary: bool,
+    pub all_of: Option<Vec<FileConditions>>,
+    pub any_of: Option<Vec<FileConditions>>,
+    pub not: Option<Box<FileConditions>>,
 }
 
 #[derive(Debug, Clone)]
@@ -51,11 +97,82 @@ pub enum Matches {
     All(Vec<String>),
 }
 
+/// Lines where the content patterns are searched, 1-based and inclusive
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineScope {
+    pub start: usize,
+    pub end: usize,
+}
+
 #[derive(Debug, Clone)]
 pub struct ContentMatches {
     pub matches: Matches,
     pub at_least: usize,
     pub at_most: Option<usize>,
+    pub lines: Option<LineScope>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ContentNotMatches {
+    pub pattern: String,
+    pub lines: Option<LineScope>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DataMatches {
+    /// Selector as written in the config, used in the error messages
+    pub path: String,
+    /// JSON pointer of the selected value, JSONPath-style selectors are
+    /// converted when the config is loaded
+    pub pointer: String,
+    pub equals: Option<serde_json::Value>,
+    pub matches: Option<String>,
+    pub exists: Option<bool>,
+    pub type_is: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct HeadingPattern {
+    /// Headings of any level are counted if not set
+    pub level: Option<u8>,
+    /// Expected heading text, or a regex if it starts with `regex:`
+    pub text: Option<String>,
+    pub at_least: usize,
+    pub at_most: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub enum JsonSchema {
+    /// Path of a schema file of the project, relative to the root folder
+    Path(String),
+    Inline(serde_json::Value),
+}
+
+#[derive(Debug, Clone)]
+pub struct DuplicateContent {
+    /// Files of any type with at least this size are also checked, by default
+    /// only the files in `analyze_content_of_files_types` are
+    pub min_size: Option<u64>,
+    /// Duplicates are reported only if they are in the same scope
+    pub scopes: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct UniqueNameScope {
+    /// Folder path or path pattern relative to the root folder
+    pub scope: String,
+    /// Name patterns that don't need to be unique
+    pub ignore: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SiblingPattern {
+    pub pattern: String,
+    pub at_least: usize,
+    pub at_most: Option<usize>,
+    /// The pattern only form of `have_sibling_file` also matches the file
+    /// itself, as it always did
+    pub match_file_itself: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -79,18 +196,41 @@ pub struct TsFileExpect {
 
 #[derive(Debug, Clone)]
 pub struct FileExpect {
-    pub name_case_is: Option<NameCase>,
+    pub name_case_is: Option<Vec<NameCase>>,
     pub extension_is: Option<Vec<String>>,
-    pub have_sibling_file: Option<String>,
+    pub suffix_is: Option<Vec<String>>,
+    pub have_sibling_file: Option<SiblingPattern>,
+    pub have_sibling_folder: Option<SiblingPattern>,
+    pub not_have_sibling_file: Option<String>,
+    pub not_have_sibling_folder: Option<String>,
+    /// Path template relative to the file, or to the root if it starts with `/`
+    pub have_companion_file: Option<String>,
     pub content_matches: Option<Vec<ContentMatches>>,
     pub content_matches_some: Option<Vec<ContentMatches>>,
-    pub content_not_matches: Option<Vec<String>>,
+    pub content_not_matches: Option<Vec<ContentNotMatches>>,
     pub name_is: Option<String>,
     pub name_is_not: Option<String>,
+    pub name_is_unique_in: Option<UniqueNameScope>,
     pub ts: Option<TsFileExpect>,
     pub is_not_empty: bool,
+    pub max_size: Option<u64>,
+    pub max_lines: Option<usize>,
+    pub min_lines: Option<usize>,
+    pub encoding_is: Option<String>,
+    pub content_is_unique: bool,
+    pub json_matches: Option<Vec<DataMatches>>,
+    pub yaml_matches: Option<Vec<DataMatches>>,
+    pub json_schema: Option<JsonSchema>,
+    pub front_matter_has: Option<Vec<String>>,
+    pub has_heading: Option<Vec<HeadingPattern>>,
+    pub relative_links_resolve: bool,
+    pub all_of: Option<Vec<FileExpect>>,
+    pub any_of: Option<Vec<FileExpect>>,
+    pub not: Option<Box<FileExpect>>,
 
     pub error_msg: Option<String>,
+    /// Overrides the rule severity for the problems of this expect
+    pub severity: Option<Severity>,
 }
 
 #[derive(Debug, Clone)]
@@ -102,23 +242,50 @@ pub struct RootFilesFindPattern {
 
 #[derive(Debug, Clone)]
 pub struct FolderConditions {
-    pub has_name_case: Option<NameCase>,
+    pub has_name_case: Option<Vec<NameCase>>,
     pub has_name: Option<String>,
     pub not_has_name: Option<String>,
+    /// Pattern matched against the path relative to the root folder
+    pub has_path: Option<String>,
+    pub not_has_path: Option<String>,
+    /// Pattern matched against the ancestor folders, at any depth
+    pub in_folder: Option<String>,
+    pub not_in_folder: Option<String>,
     pub root_files_find_pattern: Option<RootFilesFindPattern>,
+    pub is_symlink: bool,
+    pub depth_is: Option<usize>,
+    pub depth_at_least: Option<usize>,
+    /// If true the depth is counted from the root folder instead of the folder
+    /// where the rule is configured
+    pub depth_from_root: bool,
+    pub all_of: Option<Vec<FolderConditions>>,
+    pub any_of: Option<Vec<FolderConditions>>,
+    pub not: Option<Box<FolderConditions>>,
 }
 
 #[derive(Debug, Clone)]
 pub struct FolderExpect {
-    pub name_case_is: Option<NameCase>,
+    pub name_case_is: Option<Vec<NameCase>>,
     pub name_is: Option<String>,
     pub name_is_not: Option<String>,
+    pub name_is_unique_in: Option<UniqueNameScope>,
     pub root_files_has: Option<String>,
     pub root_files_has_not: Option<String>,
     pub have_min_children: Option<usize>,
+    /// Names of the placeholder files that don't count as folder content
+    pub is_not_empty: Option<Vec<String>>,
+    pub max_children: Option<usize>,
+    pub max_files: Option<usize>,
+    pub max_folders: Option<usize>,
+    pub max_depth: Option<usize>,
     pub child_rules: Option<(Vec<FolderRule>, Vec<FileRule>)>,
+    pub all_of: Option<Vec<FolderExpect>>,
+    pub any_of: Option<Vec<FolderExpect>>,
+    pub not: Option<Box<FolderExpect>>,
 
     pub error_msg: Option<String>,
+    /// Overrides the rule severity for the problems of this expect
+    pub severity: Option<Severity>,
 }
 
 #[derive(Debug, Clone)]
@@ -129,7 +296,9 @@ pub struct FileRule {
     pub not_touch: bool,
     pub ignore_in_config_tests: bool,
     pub error_msg: Option<String>,
-    pub is_warning: bool,
+    /// Rules from blocks without an id use the block name
+    pub id: Option<String>,
+    pub severity: Severity,
 }
 
 #[derive(Debug, Clone)]
@@ -141,20 +310,29 @@ pub struct FolderRule {
     pub allow_unexpected_files: bool,
     pub allow_unexpected_folders: bool,
     pub error_msg: Option<String>,
-    pub is_warning: bool,
+    /// Rules from blocks without an id use the block name
+    pub id: Option<String>,
+    pub severity: Severity,
 }
 
 #[derive(Debug, Default, Clone)]
 pub struct OneOfFile {
     pub rules: Vec<FileRule>,
     pub error_msg: String,
+    /// Id and severity of the `expect_one_of` rule, used to report the
+    /// `error_msg`
+    pub id: Option<String>,
+    pub severity: Severity,
 }
 
 #[derive(Debug, Default, Clone)]
-#[allow(dead_code)]
 pub struct OneOfFolder {
     pub rules: Vec<FolderRule>,
     pub error_msg: String,
+    /// Id and severity of the `expect_one_of` rule, used to report the
+    /// `error_msg`
+    pub id: Option<String>,
+    pub severity: Severity,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -176,6 +354,9 @@ pub struct FolderConfig {
     pub unexpected_folders_error_msg: Option<String>,
     pub unexpected_error_msg: Option<String>,
     pub append_error_msg: Option<String>,
+    /// Report names that collide case-insensitively, inherited by the sub
+    /// folders
+    pub case_collisions: Option<bool>,
 }
 
 #[derive(Debug, Clone)]
@@ -186,14 +367,76 @@ pub struct TsConfig {
 
 pub type ErrorMsgVars = Option<BTreeMap<String, String>>;
 
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum DotfilesPolicy {
+    #[default]
+    Include,
+    Exclude,
+    OnlyWhenConfigured,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum SymlinksPolicy {
+    #[default]
+    Follow,
+    Skip,
+    Report,
+}
+
+/// How the problems of a rule or expect are reported, `warn` problems are
+/// reported as errors unless warnings are allowed
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Severity {
+    Off,
+    Info,
+    Warn,
+    #[default]
+    Error,
+}
+
+impl FromStr for Severity {
+    type Err = String;
+
+    fn from_str(severity: &str) -> Result<Self, Self::Err> {
+        match severity {
+            "off" => Ok(Severity::Off),
+            "info" => Ok(Severity::Info),
+            "warn" => Ok(Severity::Warn),
+            "error" => Ok(Severity::Error),
+            _ => Err(format!(
+                "Invalid severity '{}', should be 'off', 'info', 'warn' or 'error'",
+                severity
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Acronyms {
+    pub list: Vec<String>,
+    pub strict: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct Config {
     pub root_folder: FolderConfig,
     pub analyze_content_of_files_types: Vec<String>,
     pub ignore: HashSet<String>,
+    pub use_gitignore: bool,
+    pub dotfiles: DotfilesPolicy,
+    pub symlinks: SymlinksPolicy,
+    /// Used to decode the files that are not valid UTF-8, they are decoded
+    /// lossily if not set
+    pub encoding: Option<&'static Encoding>,
+    /// Names of the placeholder files ignored when reporting empty folders
+    pub report_empty_folders: Option<Vec<String>>,
+    pub duplicate_content: Option<DuplicateContent>,
     pub ts_config: Option<TsConfig>,
     pub error_msg_vars: ErrorMsgVars,
     pub allow_warnings: bool,
+    pub acronyms: Acronyms,
+    /// Severities set with `--severity`, by rule id
+    pub severity_overrides: HashMap<String, Severity>,
 }
 
 fn normalize_single_or_multiple<T: Clone>(
@@ -238,23 +481,87 @@ fn check_any_or_none<T>(
     }
 }
 
+fn get_builtin_name_case(name_case: &str) -> Option<NameCase> {
+    match name_case {
+        "camelCase" => Some(NameCase::Camel),
+        "snake_case" => Some(NameCase::Snake),
+        "kebab-case" => Some(NameCase::Kebab),
+        "PascalCase" => Some(NameCase::Pascal),
+        "CONSTANT_CASE" => Some(NameCase::Constant),
+        "dot.case" => Some(NameCase::Dot),
+        "Train-Case" => Some(NameCase::Train),
+        "Title Case" => Some(NameCase::Title),
+        "lowercase" => Some(NameCase::Lower),
+        "flatcase" => Some(NameCase::Flat),
+        _ => None,
+    }
+}
+
+fn get_custom_name_case(name: &str, pattern: &str) -> Result<NameCase, String> {
+    if get_builtin_name_case(name).is_some() {
+        return Err(format!(
+            "Config error: Custom name case '{}' conflicts with a built-in name case",
+            name
+        ));
+    }
+
+    let regex = match pattern.strip_prefix("regex:") {
+        Some(regex) => regex.to_string(),
+        None => format!("^{}$", escape(pattern).replace("\\*", ".+")),
+    };
+
+    Regex::new(&regex).map_err(|err| {
+        format!(
+            "Config error: Invalid pattern '{}' for custom name case '{}': {}",
+            pattern, name, err
+        )
+    })?;
+
+    Ok(NameCase::Custom {
+        name: name.to_string(),
+        regex,
+    })
+}
+
 fn normalize_name_case(
     name_case: &String,
     config_path: &String,
+    parsed_config: &ParsedConfig,
 ) -> Result<NameCase, String> {
-    match name_case.as_str() {
-        "camelCase" => Ok(NameCase::Camel),
-        "snake_case" => Ok(NameCase::Snake),
-        "kebab-case" => Ok(NameCase::Kebab),
-        "PascalCase" => Ok(NameCase::Pascal),
-        "CONSTANT_CASE" => Ok(NameCase::Constant),
-        _ => Err(format!(
+    if let Some(name_case) = get_builtin_name_case(name_case) {
+        return Ok(name_case);
+    }
+
+    match parsed_config
+        .name_cases
+        .as_ref()
+        .and_then(|name_cases| name_cases.get(name_case))
+    {
+        Some(pattern) => get_custom_name_case(name_case, pattern),
+        None => Err(format!(
             "Config error: Invalid name_case_is '{}' in '{}' rules",
             name_case, config_path
         )),
     }
 }
 
+fn normalize_name_cases(
+    name_cases: &Option<SingleOrMultiple<String>>,
+    config_path: &String,
+    parsed_config: &ParsedConfig,
+) -> Result<Option<Vec<NameCase>>, String> {
+    normalize_single_or_multiple_option(name_cases)
+        .map(|name_cases| {
+            name_cases
+                .iter()
+                .map(|name_case| {
+                    normalize_name_case(name_case, config_path, parsed_config)
+                })
+                .collect::<Result<Vec<NameCase>, String>>()
+        })
+        .transpose()
+}
+
 fn check_invalid_conditions(
     extra_conditions: &HashMap<String, Value>,
     condition_type: &str,
@@ -276,6 +583,74 @@ fn check_invalid_conditions(
     Ok(())
 }
 
+fn get_dotfiles_policy(dotfiles: &Option<String>) -> Result<DotfilesPolicy, String> {
+    match dotfiles.as_deref() {
+        None | Some("include") => Ok(DotfilesPolicy::Include),
+        Some("exclude") => Ok(DotfilesPolicy::Exclude),
+        Some("only_when_configured") => Ok(DotfilesPolicy::OnlyWhenConfigured),
+        Some(dotfiles) => Err(format!(
+            "Config error: Invalid dotfiles policy '{}', should be 'include', 'exclude' or 'only_when_configured'",
+            dotfiles
+        )),
+    }
+}
+
+fn get_symlinks_policy(symlinks: &Option<String>) -> Result<SymlinksPolicy, String> {
+    match symlinks.as_deref() {
+        None | Some("follow") => Ok(SymlinksPolicy::Follow),
+        Some("skip") => Ok(SymlinksPolicy::Skip),
+        Some("report") => Ok(SymlinksPolicy::Report),
+        Some(symlinks) => Err(format!(
+            "Config error: Invalid symlinks policy '{}', should be 'follow', 'skip' or 'report'",
+            symlinks
+        )),
+    }
+}
+
+fn get_encoding(
+    encoding: &Option<String>,
+) -> Result<Option<&'static Encoding>, String> {
+    encoding
+        .as_ref()
+        .map(|label| {
+            Encoding::for_label(label.as_bytes())
+                .ok_or_else(|| format!("Config error: Invalid encoding '{}'", label))
+        })
+        .transpose()
+}
+
+fn get_acronyms(parsed_config: &ParsedConfig) -> Result<Acronyms, String> {
+    let list = parsed_config.acronyms.clone().unwrap_or_default();
+
+    for acronym in &list {
+        if acronym.len() < 2
+            || !acronym
+                .chars()
+                .all(|char| char.is_ascii_uppercase() || char.is_ascii_digit())
+        {
+            return Err(format!(
+                "Config error: Invalid acronym '{}', acronyms should have at least 2 uppercase letters or digits",
+                acronym
+            ));
+        }
+    }
+
+    let strict = get_true_flag(
+        &String::from("."),
+        &parsed_config.strict_acronyms,
+        "strict_acronyms",
+    )?;
+
+    if strict && list.is_empty() {
+        return Err(
+            "Config error: 'strict_acronyms' can only be used with 'acronyms'"
+                .to_string(),
+        );
+    }
+
+    Ok(Acronyms { list, strict })
+}
+
 fn get_true_flag(
     config_path: &String,
     flag: &Option<bool>,
@@ -291,6 +666,330 @@ fn get_true_flag(
     }
 }
 
+fn get_severity(
+    config_path: &String,
+    severity: &Option<String>,
+) -> Result<Option<Severity>, String> {
+    severity
+        .as_deref()
+        .map(|severity| {
+            Severity::from_str(severity)
+                .map_err(|err| format!("Config error in '{}': {}", config_path, err))
+        })
+        .transpose()
+}
+
+/// `is_warning` is kept as an alias of the `warn` severity
+fn get_rule_severity(
+    config_path: &String,
+    severity: &Option<String>,
+    is_warning: &Option<bool>,
+) -> Result<Severity, String> {
+    let is_warning = get_true_flag(config_path, is_warning, "is_warning")?;
+
+    match (get_severity(config_path, severity)?, is_warning) {
+        (Some(_), true) => Err(format!(
+            "Config error in '{}': Use only one of 'severity' or 'is_warning'",
+            config_path
+        )),
+        (Some(severity), false) => Ok(severity),
+        (None, true) => Ok(Severity::Warn),
+        (None, false) => Ok(Severity::Error),
+    }
+}
+
+fn get_duplicate_content(
+    duplicate_content: &Option<ParsedDuplicateContent>,
+) -> Result<Option<DuplicateContent>, String> {
+    let config_path = String::from(".");
+
+    match duplicate_content {
+        None => Ok(None),
+        Some(ParsedDuplicateContent::Enabled(true)) => Ok(Some(DuplicateContent {
+            min_size: None,
+            scopes: vec![config_path],
+        })),
+        Some(ParsedDuplicateContent::Enabled(false)) => Err(format!(
+            "Config error in '{}': Invalid 'duplicate_content' flag with false value, remove the flag if you don't want to use it",
+            config_path
+        )),
+        Some(ParsedDuplicateContent::Config { min_size, scopes }) => {
+            Ok(Some(DuplicateContent {
+                min_size: get_file_size(&config_path, min_size, "min_size")?,
+                scopes: scopes.clone().unwrap_or_else(|| vec![config_path.clone()]),
+            }))
+        }
+    }
+}
+
+const DATA_TYPES: [&str; 7] = [
+    "string", "number", "integer", "boolean", "array", "object", "null",
+];
+
+fn escape_json_pointer_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+/// Converts a JSONPath-style selector like `$.scripts['build'].args[0]` to a
+/// JSON pointer, JSON pointers are returned as they are
+fn get_json_pointer(path: &str) -> Option<String> {
+    if path.is_empty() || path.starts_with('/') {
+        return Some(path.to_string());
+    }
+
+    let mut rest = path.strip_prefix('$')?;
+    let mut pointer = String::new();
+
+    while !rest.is_empty() {
+        if let Some(after_dot) = rest.strip_prefix('.') {
+            let key_end = after_dot.find(['.', '[']).unwrap_or(after_dot.len());
+
+            if key_end == 0 {
+                return None;
+            }
+
+            pointer.push('/');
+            pointer.push_str(&escape_json_pointer_token(&after_dot[..key_end]));
+            rest = &after_dot[key_end..];
+        } else if let Some(after_bracket) = rest.strip_prefix('[') {
+            let quote = after_bracket.chars().next()?;
+
+            let (token, after_token) = if quote == '\'' || quote == '"' {
+                let key_end = after_bracket[1..].find(quote)? + 1;
+
+                (&after_bracket[1..key_end], &after_bracket[key_end + 1..])
+            } else {
+                let index_end = after_bracket.find(']')?;
+                let index = &after_bracket[..index_end];
+
+                if index.is_empty()
+                    || !index.chars().all(|char| char.is_ascii_digit())
+                {
+                    return None;
+                }
+
+                (index, &after_bracket[index_end..])
+            };
+
+            pointer.push('/');
+            pointer.push_str(&escape_json_pointer_token(token));
+            rest = after_token.strip_prefix(']')?;
+        } else {
+            return None;
+        }
+    }
+
+    Some(pointer)
+}
+
+fn get_data_matches(
+    parsed_data_matches: &Option<SingleOrMultiple<ParsedDataMatches>>,
+    expect_name: &str,
+    config_path: &String,
+) -> Result<Option<Vec<DataMatches>>, String> {
+    normalize_single_or_multiple_option(parsed_data_matches)
+        .map(|items| {
+            items
+                .into_iter()
+                .map(|item| {
+                    let pointer = get_json_pointer(&item.path).ok_or_else(|| {
+                        format!(
+                            "Config error in '{}': Invalid path '{}' in '{}', use a JSON pointer like '/name' or a simple JSONPath like '$.name'",
+                            config_path, item.path, expect_name
+                        )
+                    })?;
+
+                    if let Some(type_is) = &item.type_is {
+                        if !DATA_TYPES.contains(&type_is.as_str()) {
+                            return Err(format!(
+                                "Config error in '{}': Invalid type '{}' in '{}', use one of: {}",
+                                config_path,
+                                type_is,
+                                expect_name,
+                                DATA_TYPES.join(", ")
+                            ));
+                        }
+                    }
+
+                    Ok(DataMatches {
+                        path: item.path,
+                        pointer,
+                        equals: item.equals,
+                        matches: item.matches,
+                        exists: item.exists,
+                        type_is: item.type_is,
+                    })
+                })
+                .collect()
+        })
+        .transpose()
+}
+
+fn get_json_schema(
+    parsed_json_schema: &Option<ParsedJsonSchema>,
+) -> Option<JsonSchema> {
+    parsed_json_schema
+        .as_ref()
+        .map(|json_schema| match json_schema {
+            ParsedJsonSchema::Path(path) => JsonSchema::Path(path.clone()),
+            ParsedJsonSchema::Inline(schema) => JsonSchema::Inline(schema.clone()),
+        })
+}
+
+fn get_heading_patterns(
+    parsed_heading_patterns: &Option<SingleOrMultiple<ParsedHeadingPattern>>,
+    config_path: &String,
+) -> Result<Option<Vec<HeadingPattern>>, String> {
+    normalize_single_or_multiple_option(parsed_heading_patterns)
+        .map(|items| {
+            items
+                .into_iter()
+                .map(|item| match item {
+                    ParsedHeadingPattern::Text(text) => Ok(HeadingPattern {
+                        level: None,
+                        text: Some(text),
+                        at_least: 1,
+                        at_most: None,
+                    }),
+                    ParsedHeadingPattern::Config {
+                        level,
+                        text,
+                        at_least,
+                        at_most,
+                    } => {
+                        if let Some(level) = level {
+                            if !(1..=6).contains(&level) {
+                                return Err(format!(
+                                    "Config error in '{}': Invalid heading level '{}' in 'has_heading', use a level from 1 to 6",
+                                    config_path, level
+                                ));
+                            }
+                        }
+
+                        Ok(HeadingPattern {
+                            level,
+                            text,
+                            at_least: at_least.unwrap_or(1),
+                            at_most,
+                        })
+                    }
+                })
+                .collect()
+        })
+        .transpose()
+}
+
+/// Index files are ignored by default, they are expected to repeat
+fn get_unique_name_scope(
+    unique_name_scope: &Option<ParsedUniqueNameScope>,
+) -> Option<UniqueNameScope> {
+    let default_ignore = || vec!["index".to_string(), "index.*".to_string()];
+
+    unique_name_scope
+        .as_ref()
+        .map(|unique_name_scope| match unique_name_scope {
+            ParsedUniqueNameScope::Scope(scope) => UniqueNameScope {
+                scope: scope.clone(),
+                ignore: default_ignore(),
+            },
+            ParsedUniqueNameScope::Config { scope, ignore } => UniqueNameScope {
+                scope: scope.clone(),
+                ignore: ignore.clone().unwrap_or_else(default_ignore),
+            },
+        })
+}
+
+fn get_sibling_pattern(
+    sibling_pattern: &Option<ParsedSiblingPattern>,
+) -> Option<SiblingPattern> {
+    sibling_pattern
+        .as_ref()
+        .map(|sibling_pattern| match sibling_pattern {
+            ParsedSiblingPattern::Pattern(pattern) => SiblingPattern {
+                pattern: pattern.clone(),
+                at_least: 1,
+                at_most: None,
+                match_file_itself: true,
+            },
+            ParsedSiblingPattern::Config {
+                pattern,
+                at_least,
+                at_most,
+            } => SiblingPattern {
+                pattern: pattern.clone(),
+                at_least: at_least.unwrap_or(1),
+                at_most: *at_most,
+                match_file_itself: false,
+            },
+        })
+}
+
+/// Returns the placeholder file names that are ignored when checking if a
+/// folder is empty, `.gitkeep` and `.keep` are used if not configured
+fn get_empty_folder_placeholders(
+    config_path: &String,
+    check: &Option<ParsedEmptyFolderCheck>,
+    option_name: &str,
+) -> Result<Option<Vec<String>>, String> {
+    match check {
+        None => Ok(None),
+        Some(ParsedEmptyFolderCheck::Enabled(true)) => Ok(Some(vec![
+            ".gitkeep".to_string(),
+            ".keep".to_string(),
+        ])),
+        Some(ParsedEmptyFolderCheck::Enabled(false)) => Err(format!(
+            "Config error in '{}': Invalid '{}' flag with false value, remove the flag if you don't want to use it",
+            config_path, option_name
+        )),
+        Some(ParsedEmptyFolderCheck::Config { ignore }) => Ok(Some(ignore.clone())),
+    }
+}
+
+/// Converts sizes like `200kb` or `1.5mb` to bytes, units are multiples of 1024
+fn get_file_size(
+    config_path: &String,
+    size: &Option<ParsedFileSize>,
+    option_name: &str,
+) -> Result<Option<u64>, String> {
+    let size = match size {
+        Some(ParsedFileSize::Bytes(bytes)) => return Ok(Some(*bytes)),
+        Some(ParsedFileSize::WithUnit(size)) => size,
+        None => return Ok(None),
+    };
+
+    let invalid_size_error = || {
+        format!(
+            "Config error in '{}': Invalid '{}' value '{}', use a number of bytes or a size like '200kb' (units: b, kb, mb, gb)",
+            config_path, option_name, size
+        )
+    };
+
+    let normalized_size = size.trim().to_lowercase();
+
+    let unit_start = normalized_size
+        .find(|char: char| char.is_ascii_alphabetic())
+        .unwrap_or(normalized_size.len());
+
+    let (value, unit) = normalized_size.split_at(unit_start);
+
+    let multiplier: u64 = match unit {
+        "" | "b" => 1,
+        "kb" => 1024,
+        "mb" => 1024 * 1024,
+        "gb" => 1024 * 1024 * 1024,
+        _ => return Err(invalid_size_error()),
+    };
+
+    let value = value
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite() && *value >= 0.0)
+        .ok_or_else(invalid_size_error)?;
+
+    Ok(Some((value * multiplier as f64).round() as u64))
+}
+
 type NormalizedBlocks = BTreeMap<String, Vec<ParsedRule>>;
 
 fn normalize_rules(
@@ -315,25 +1014,17 @@ fn normalize_rules(
                 not_touch,
                 ignore_in_config_tests,
                 is_warning,
+                id,
+                severity,
             } => {
                 let conditions = match parsed_conditions {
                     ParsedAnyNoneOrConditions::AnyOrNone(any) => {
                         check_any(any, config_path)?;
                         AnyOr::Any
                     }
-                    ParsedAnyNoneOrConditions::Conditions(conditions) => {
-                        check_invalid_conditions(
-                            &conditions.wrong,
-                            "if_file condition",
-                            config_path,
-                        )?;
-
-                        AnyOr::Or(get_file_conditions(
-                            conditions,
-                            config_path,
-                            config,
-                        )?)
-                    }
+                    ParsedAnyNoneOrConditions::Conditions(conditions) => AnyOr::Or(
+                        get_file_conditions(conditions, config_path, config)?,
+                    ),
                 };
 
                 check_rules_expects(expect, expect_one_of, config_path)?;
@@ -385,10 +1076,11 @@ fn normalize_rules(
                             ignore_in_config_tests,
                             "ignore_in_config_tests",
                         )?,
-                        is_warning: get_true_flag(
+                        id: id.clone(),
+                        severity: get_rule_severity(
                             config_path,
+                            severity,
                             is_warning,
-                            "is_warning",
                         )?,
                     });
                 };
@@ -404,13 +1096,17 @@ fn normalize_rules(
                         let mut rules: Vec<FileRule> = Vec::new();
 
                         for rule in expect_one_of {
+                            let rule_expect =
+                                get_file_expect(rule.clone(), config_path, config)?;
+
+                            check_one_of_expect_severity(
+                                &rule_expect.severity,
+                                config_path,
+                            )?;
+
                             rules.push(FileRule {
                                 conditions: conditions.clone(),
-                                expect: AnyNoneOr::Or(vec![get_file_expect(
-                                    rule.clone(),
-                                    config_path,
-                                    config,
-                                )?]),
+                                expect: AnyNoneOr::Or(vec![rule_expect]),
                                 not_touch: get_true_flag(
                                     config_path,
                                     not_touch,
@@ -427,17 +1123,20 @@ fn normalize_rules(
                                     ignore_in_config_tests,
                                     "ignore_in_config_tests",
                                 )?,
-                                is_warning: get_true_flag(
-                                    config_path,
-                                    is_warning,
-                                    "is_warning",
-                                )?,
+                                id: None,
+                                severity: Severity::Error,
                             });
                         }
 
                         let one_of = OneOfFile {
                             error_msg: error_msg.clone(),
                             rules,
+                            id: id.clone(),
+                            severity: get_rule_severity(
+                                config_path,
+                                severity,
+                                is_warning,
+                            )?,
                         };
 
                         one_of_file_blocks.push(one_of);
@@ -460,45 +1159,17 @@ fn normalize_rules(
                 non_recursive,
                 not_touch,
                 is_warning,
+                id,
+                severity,
             } => {
                 let conditions = match parsed_conditions {
                     ParsedAnyNoneOrConditions::AnyOrNone(any) => {
                         check_any(any, config_path)?;
                         AnyOr::Any
                     }
-                    ParsedAnyNoneOrConditions::Conditions(conditions) => {
-                        check_invalid_conditions(
-                            &conditions.wrong,
-                            "if_folder condition",
-                            config_path,
-                        )?;
-
-                        AnyOr::Or(FolderConditions {
-                            has_name_case: conditions
-                                .has_name_case
-                                .as_ref()
-                                .map(|name_case| {
-                                    normalize_name_case(name_case, config_path)
-                                })
-                                .transpose()?,
-                            has_name: conditions.has_name.clone(),
-                            not_has_name: conditions.not_has_name.clone(),
-                            root_files_find_pattern: conditions
-                                .root_files_find_pattern
-                                .as_ref()
-                                .map(|root_files_find_pattern| {
-                                    RootFilesFindPattern {
-                                        pattern: root_files_find_pattern
-                                            .pattern
-                                            .clone(),
-                                        at_least: root_files_find_pattern
-                                            .at_least
-                                            .unwrap_or(1),
-                                        at_most: root_files_find_pattern.at_most,
-                                    }
-                                }),
-                        })
-                    }
+                    ParsedAnyNoneOrConditions::Conditions(conditions) => AnyOr::Or(
+                        get_folder_conditions(conditions, config_path, config)?,
+                    ),
                 };
 
                 check_rules_expects(expect, expect_one_of, config_path)?;
@@ -571,10 +1242,11 @@ fn normalize_rules(
                             non_recursive,
                             "non_recursive",
                         )?,
-                        is_warning: get_true_flag(
+                        id: id.clone(),
+                        severity: get_rule_severity(
                             config_path,
+                            severity,
                             is_warning,
-                            "is_warning",
                         )?,
                     });
                 }
@@ -590,14 +1262,21 @@ fn normalize_rules(
                         let mut rules: Vec<FolderRule> = Vec::new();
 
                         for rule_expect in expect_one_of {
+                            let rule_expect = get_folder_expect(
+                                rule_expect.clone(),
+                                config_path,
+                                config,
+                                normalized_blocks,
+                            )?;
+
+                            check_one_of_expect_severity(
+                                &rule_expect.severity,
+                                config_path,
+                            )?;
+
                             rules.push(FolderRule {
                                 conditions: conditions.clone(),
-                                expect: AnyNoneOr::Or(vec![get_folder_expect(
-                                    rule_expect.clone(),
-                                    config_path,
-                                    config,
-                                    normalized_blocks,
-                                )?]),
+                                expect: AnyNoneOr::Or(vec![rule_expect]),
                                 not_touch: get_true_flag(
                                     config_path,
                                     not_touch,
@@ -612,17 +1291,20 @@ fn normalize_rules(
                                     non_recursive,
                                     "non_recursive",
                                 )?,
-                                is_warning: get_true_flag(
-                                    config_path,
-                                    is_warning,
-                                    "is_warning",
-                                )?,
+                                id: None,
+                                severity: Severity::Error,
                             });
                         }
 
                         let one_of = OneOfFolder {
                             error_msg: error_msg.clone(),
                             rules,
+                            id: id.clone(),
+                            severity: get_rule_severity(
+                                config_path,
+                                severity,
+                                is_warning,
+                            )?,
                         };
 
                         one_of_folder_blocks.push(one_of);
@@ -630,12 +1312,19 @@ fn normalize_rules(
                 }
             }
             ParsedRule::Block(block_string) => {
-                let (block_id, custom_error, custom_not_touch, custom_non_recursive) = {
+                let (
+                    block_id,
+                    custom_error,
+                    custom_not_touch,
+                    custom_non_recursive,
+                    custom_severity,
+                ) = {
                     if block_string.contains("::") {
                         let mut block_id = String::new();
                         let mut custom_error: Option<String> = None;
                         let mut custom_not_touch = None;
                         let mut custom_non_recursive = None;
+                        let mut custom_severity: Option<String> = None;
 
                         for (i, part) in block_string.split("::").enumerate() {
                             if i == 0 {
@@ -657,6 +1346,10 @@ fn normalize_rules(
                                     } else {
                                         Some(true)
                                     };
+                            } else if part.starts_with("severity=") {
+                                custom_severity = part
+                                    .strip_prefix("severity=")
+                                    .map(|s| s.to_string());
                             }
                         }
 
@@ -665,9 +1358,10 @@ fn normalize_rules(
                             custom_error,
                             custom_not_touch,
                             custom_non_recursive,
+                            custom_severity,
                         )
                     } else {
-                        (block_string.clone(), None, None, None)
+                        (block_string.clone(), None, None, None, None)
                     }
                 };
 
@@ -688,6 +1382,8 @@ fn normalize_rules(
                             error_msg,
                             ignore_in_config_tests,
                             is_warning,
+                            id,
+                            severity,
                         } => ParsedRule::File {
                             conditions: conditions.clone(),
                             expect: expect.clone(),
@@ -696,7 +1392,10 @@ fn normalize_rules(
                             not_touch: custom_not_touch.or(*not_touch),
                             error_msg: custom_error.clone().or(error_msg.clone()),
                             ignore_in_config_tests: *ignore_in_config_tests,
-                            is_warning: *is_warning,
+                            is_warning: is_warning
+                                .filter(|_| custom_severity.is_none()),
+                            id: id.clone().or(Some(block_id.clone())),
+                            severity: custom_severity.clone().or(severity.clone()),
                         },
                         ParsedRule::Folder {
                             conditions,
@@ -709,6 +1408,8 @@ fn normalize_rules(
                             not_touch,
                             error_msg,
                             is_warning,
+                            id,
+                            severity,
                         } => ParsedRule::Folder {
                             conditions: conditions.clone(),
                             expect: expect.clone(),
@@ -719,7 +1420,10 @@ fn normalize_rules(
                             non_recursive: custom_non_recursive.or(*non_recursive),
                             not_touch: custom_not_touch.or(*not_touch),
                             error_msg: custom_error.clone().or(error_msg.clone()),
-                            is_warning: *is_warning,
+                            is_warning: is_warning
+                                .filter(|_| custom_severity.is_none()),
+                            id: id.clone().or(Some(block_id.clone())),
+                            severity: custom_severity.clone().or(severity.clone()),
                         },
                         _ => rule.clone(),
                     })
@@ -748,6 +1452,15 @@ fn normalize_rules(
                             ));
                         }
 
+                        if let ParsedRule::File { id: Some(_), .. }
+                        | ParsedRule::Folder { id: Some(_), .. } = rule
+                        {
+                            return Err(format!(
+                                "Config error in '{}': 'id' and 'severity' cannot be used in the rules inside 'one_of'",
+                                config_path
+                            ));
+                        }
+
                         let (and_file_rules, and_folder_rules, _) = normalize_rules(
                             &vec![rule.clone()],
                             config_path,
@@ -777,6 +1490,20 @@ fn normalize_rules(
                                 config_path
                             ));
                             }
+
+                            check_one_of_rule_severity(
+                                and_file_rule.severity,
+                                config_path,
+                            )?;
+
+                            if let AnyNoneOr::Or(expects) = &and_file_rule.expect {
+                                for expect in expects {
+                                    check_one_of_expect_severity(
+                                        &expect.severity,
+                                        config_path,
+                                    )?;
+                                }
+                            }
                         }
 
                         for and_folder_rule in &and_folder_rules {
@@ -786,6 +1513,20 @@ fn normalize_rules(
                                 config_path
                             ));
                             }
+
+                            check_one_of_rule_severity(
+                                and_folder_rule.severity,
+                                config_path,
+                            )?;
+
+                            if let AnyNoneOr::Or(expects) = &and_folder_rule.expect {
+                                for expect in expects {
+                                    check_one_of_expect_severity(
+                                        &expect.severity,
+                                        config_path,
+                                    )?;
+                                }
+                            }
                         }
 
                         if (!and_file_rules.is_empty() && !one_of_folder.is_empty())
@@ -815,11 +1556,15 @@ fn normalize_rules(
                         one_of_file_blocks.push(OneOfFile {
                             rules: one_of_file,
                             error_msg: error_msg.clone(),
+                            id: None,
+                            severity: Severity::Error,
                         });
                     } else {
                         one_of_folder_blocks.push(OneOfFolder {
                             rules: one_of_folder,
                             error_msg: error_msg.clone(),
+                            id: None,
+                            severity: Severity::Error,
                         });
                     }
                 } else {
@@ -876,18 +1621,72 @@ fn get_folder_expect(
     parsed_config: &ParsedConfig,
     normalized_blocks: &NormalizedBlocks,
 ) -> Result<FolderExpect, String> {
+    let get_nested_expect =
+        |nested_expect: &ParsedFolderExpect| -> Result<FolderExpect, String> {
+            check_invalid_conditions(
+                &nested_expect.wrong,
+                "folder expect condition",
+                config_path,
+            )?;
+
+            if nested_expect.child_rules.is_some() {
+                return Err(format!(
+                    "Config error in '{}': 'child_rules' cannot be used inside 'all_of', 'any_of' or 'not'",
+                    config_path
+                ));
+            }
+
+            check_nested_expect_severity(&nested_expect.severity, config_path)?;
+
+            get_folder_expect(
+                nested_expect.clone(),
+                config_path,
+                parsed_config,
+                normalized_blocks,
+            )
+        };
+
     Ok(FolderExpect {
         error_msg: parsed_expected.error_msg.clone(),
+        severity: get_severity(config_path, &parsed_expected.severity)?,
+        all_of: get_nested_expects(
+            &parsed_expected.all_of,
+            "all_of",
+            config_path,
+            get_nested_expect,
+        )?,
+        any_of: get_nested_expects(
+            &parsed_expected.any_of,
+            "any_of",
+            config_path,
+            get_nested_expect,
+        )?,
+        not: parsed_expected
+            .not
+            .as_deref()
+            .map(get_nested_expect)
+            .transpose()?
+            .map(Box::new),
         name_is: parsed_expected.name_is,
-        name_case_is: parsed_expected
-            .name_case_is
-            .as_ref()
-            .map(|name_case| normalize_name_case(name_case, config_path))
-            .transpose()?,
+        name_case_is: normalize_name_cases(
+            &parsed_expected.name_case_is,
+            config_path,
+            parsed_config,
+        )?,
         name_is_not: parsed_expected.name_is_not,
+        name_is_unique_in: get_unique_name_scope(&parsed_expected.name_is_unique_in),
         root_files_has: parsed_expected.root_files_has,
         root_files_has_not: parsed_expected.root_files_has_not,
         have_min_children: parsed_expected.have_min_children,
+        is_not_empty: get_empty_folder_placeholders(
+            config_path,
+            &parsed_expected.is_not_empty,
+            "is_not_empty",
+        )?,
+        max_children: parsed_expected.max_children,
+        max_files: parsed_expected.max_files,
+        max_folders: parsed_expected.max_folders,
+        max_depth: parsed_expected.max_depth,
         child_rules: parsed_expected
             .child_rules
             .map(
@@ -936,36 +1735,150 @@ fn get_file_expect(
         ));
     }
 
+    if (parsed_expected.front_matter_has.is_some()
+        || parsed_expected.has_heading.is_some()
+        || parsed_expected.relative_links_resolve.is_some())
+        && parsed_config.analyze_content_of_files_types.is_none()
+    {
+        return Err(format!(
+            "Config error in '{}': to use 'front_matter_has', 'has_heading' and 'relative_links_resolve' you must specify the 'analyze_content_of_files_types' property with the file extensions you want to analyze",
+            config_path
+        ));
+    }
+
+    if (parsed_expected.json_matches.is_some()
+        || parsed_expected.yaml_matches.is_some()
+        || parsed_expected.json_schema.is_some())
+        && parsed_config.analyze_content_of_files_types.is_none()
+    {
+        return Err(format!(
+            "Config error in '{}': to use 'json_matches', 'yaml_matches' and 'json_schema' you must specify the 'analyze_content_of_files_types' property with the file extensions you want to analyze",
+            config_path
+        ));
+    }
+
+    let get_nested_expect =
+        |nested_expect: &ParsedFileExpect| -> Result<FileExpect, String> {
+            check_invalid_conditions(
+                &nested_expect.wrong,
+                "file expect condition",
+                config_path,
+            )?;
+
+            check_nested_expect_severity(&nested_expect.severity, config_path)?;
+
+            get_file_expect(nested_expect.clone(), config_path, parsed_config)
+        };
+
     Ok(FileExpect {
+        all_of: get_nested_expects(
+            &parsed_expected.all_of,
+            "all_of",
+            config_path,
+            get_nested_expect,
+        )?,
+        any_of: get_nested_expects(
+            &parsed_expected.any_of,
+            "any_of",
+            config_path,
+            get_nested_expect,
+        )?,
+        not: parsed_expected
+            .not
+            .as_deref()
+            .map(get_nested_expect)
+            .transpose()?
+            .map(Box::new),
         error_msg: parsed_expected.error_msg,
+        severity: get_severity(config_path, &parsed_expected.severity)?,
         name_is: parsed_expected.name_is,
         extension_is: normalize_single_or_multiple_option(
             &parsed_expected.extension_is,
         ),
-        name_case_is: parsed_expected
-            .name_case_is
-            .as_ref()
-            .map(|name_case| normalize_name_case(name_case, config_path))
-            .transpose()?,
-        have_sibling_file: parsed_expected.have_sibling_file,
+        suffix_is: normalize_single_or_multiple_option(&parsed_expected.suffix_is),
+        name_case_is: normalize_name_cases(
+            &parsed_expected.name_case_is,
+            config_path,
+            parsed_config,
+        )?,
+        have_sibling_file: get_sibling_pattern(&parsed_expected.have_sibling_file),
+        have_sibling_folder: get_sibling_pattern(
+            &parsed_expected.have_sibling_folder,
+        ),
+        not_have_sibling_file: parsed_expected.not_have_sibling_file,
+        not_have_sibling_folder: parsed_expected.not_have_sibling_folder,
+        have_companion_file: parsed_expected.have_companion_file,
         content_matches: normalize_content_matches(
             parsed_expected.content_matches,
             config_path,
-        ),
+        )?,
         content_matches_some: normalize_content_matches(
             parsed_expected.content_matches_any,
             config_path,
-        ),
-        content_not_matches: normalize_single_or_multiple_option(
+        )?,
+        content_not_matches: normalize_content_not_matches(
             &parsed_expected.content_not_matches,
-        ),
+            config_path,
+        )?,
 
         name_is_not: parsed_expected.name_is_not,
+        name_is_unique_in: get_unique_name_scope(&parsed_expected.name_is_unique_in),
         is_not_empty: get_true_flag(
             config_path,
             &parsed_expected.is_not_empty,
             "is_not_empty",
         )?,
+        max_size: get_file_size(config_path, &parsed_expected.max_size, "max_size")?,
+        max_lines: parsed_expected.max_lines,
+        min_lines: parsed_expected.min_lines,
+        encoding_is: match parsed_expected.encoding_is {
+            Some(encoding) => match encoding.to_lowercase().as_str() {
+                "utf-8" | "utf8" => Some("utf-8".to_string()),
+                _ => {
+                    return Err(format!(
+                        "Config error in '{}': Invalid 'encoding_is' value '{}', only 'utf-8' is supported",
+                        config_path, encoding
+                    ))
+                }
+            },
+            None => None,
+        },
+        content_is_unique: {
+            let content_is_unique = get_true_flag(
+                config_path,
+                &parsed_expected.content_is_unique,
+                "content_is_unique",
+            )?;
+
+            if content_is_unique && parsed_config.duplicate_content.is_none() {
+                return Err(format!(
+                    "Config error in '{}': to use 'content_is_unique' you must enable the 'duplicate_content' option",
+                    config_path
+                ));
+            }
+
+            content_is_unique
+        },
+        json_matches: get_data_matches(
+            &parsed_expected.json_matches,
+            "json_matches",
+            config_path,
+        )?,
+        yaml_matches: get_data_matches(
+            &parsed_expected.yaml_matches,
+            "yaml_matches",
+            config_path,
+        )?,
+        json_schema: get_json_schema(&parsed_expected.json_schema),
+        front_matter_has: normalize_single_or_multiple_option(
+            &parsed_expected.front_matter_has,
+        ),
+        has_heading: get_heading_patterns(&parsed_expected.has_heading, config_path)?,
+        relative_links_resolve: get_true_flag(
+            config_path,
+            &parsed_expected.relative_links_resolve,
+            "relative_links_resolve",
+        )?,
         ts: match parsed_expected.ts {
             Some(ts) => {
                 if parsed_config.ts.is_none() {
@@ -1021,15 +1934,28 @@ fn get_file_conditions(
 ) -> Result<FileConditions, String> {
     let ParsedFileConditions {
         has_extension,
+        has_suffix,
         has_name,
         not_has_name,
+        has_path,
+        not_has_path,
+        in_folder,
+        not_in_folder,
         is_ts,
+        is_symlink,
+        is_larger_than,
+        is_binary,
         has_content,
         has_any_content,
         not_has_content,
-        wrong: _,
+        all_of,
+        any_of,
+        not,
+        wrong,
     } = parsed_conditions;
 
+    check_invalid_conditions(wrong, "if_file condition", config_path)?;
+
     if (has_content.is_some()
         || has_any_content.is_some()
         || not_has_content.is_some())
@@ -1054,17 +1980,241 @@ fn get_file_conditions(
                     .unwrap_or_default(),
             ),
         ),
+        has_suffix: normalize_single_or_multiple_option(has_suffix),
         has_name: has_name.clone(),
         not_has_name: not_has_name.clone(),
-        has_content: normalize_content_matches(has_content.clone(), config_path),
+        has_path: has_path.clone(),
+        not_has_path: not_has_path.clone(),
+        in_folder: in_folder.clone(),
+        not_in_folder: not_in_folder.clone(),
+        has_content: normalize_content_matches(has_content.clone(), config_path)?,
         has_any_content: normalize_content_matches(
             has_any_content.clone(),
             config_path,
-        ),
-        not_has_content: normalize_single_or_multiple_option(not_has_content),
+        )?,
+        not_has_content: normalize_content_not_matches(
+            not_has_content,
+            config_path,
+        )?,
+        is_symlink: get_true_flag(config_path, is_symlink, "is_symlink")?,
+        is_larger_than: get_file_size(
+            config_path,
+            is_larger_than,
+            "is_larger_than",
+        )?,
+        is_binary: get_true_flag(config_path, is_binary, "is_binary")?,
+        all_of: get_nested_conditions(
+            all_of,
+            "all_of",
+            config_path,
+            |conditions| get_file_conditions(conditions, config_path, parsed_config),
+        )?,
+        any_of: get_nested_conditions(
+            any_of,
+            "any_of",
+            config_path,
+            |conditions| get_file_conditions(conditions, config_path, parsed_config),
+        )?,
+        not: not
+            .as_ref()
+            .map(|conditions| -> Result<Box<FileConditions>, String> {
+                Ok(Box::new(get_file_conditions(
+                    conditions,
+                    config_path,
+                    parsed_config,
+                )?))
+            })
+            .transpose()?,
     })
 }
 
+fn get_folder_conditions(
+    parsed_conditions: &ParsedFolderConditions,
+    config_path: &String,
+    parsed_config: &ParsedConfig,
+) -> Result<FolderConditions, String> {
+    check_invalid_conditions(
+        &parsed_conditions.wrong,
+        "if_folder condition",
+        config_path,
+    )?;
+
+    Ok(FolderConditions {
+        has_name_case: normalize_name_cases(
+            &parsed_conditions.has_name_case,
+            config_path,
+            parsed_config,
+        )?,
+        has_name: parsed_conditions.has_name.clone(),
+        not_has_name: parsed_conditions.not_has_name.clone(),
+        has_path: parsed_conditions.has_path.clone(),
+        not_has_path: parsed_conditions.not_has_path.clone(),
+        in_folder: parsed_conditions.in_folder.clone(),
+        not_in_folder: parsed_conditions.not_in_folder.clone(),
+        root_files_find_pattern: parsed_conditions
+            .root_files_find_pattern
+            .as_ref()
+            .map(|root_files_find_pattern| RootFilesFindPattern {
+                pattern: root_files_find_pattern.pattern.clone(),
+                at_least: root_files_find_pattern.at_least.unwrap_or(1),
+                at_most: root_files_find_pattern.at_most,
+            }),
+        is_symlink: get_true_flag(
+            config_path,
+            &parsed_conditions.is_symlink,
+            "is_symlink",
+        )?,
+        depth_is: parsed_conditions.depth_is,
+        depth_at_least: parsed_conditions.depth_at_least,
+        depth_from_root: get_depth_from_root(
+            config_path,
+            &parsed_conditions.depth_from,
+        )?,
+        all_of: get_nested_conditions(
+            &parsed_conditions.all_of,
+            "all_of",
+            config_path,
+            |conditions| {
+                get_folder_conditions(conditions, config_path, parsed_config)
+            },
+        )?,
+        any_of: get_nested_conditions(
+            &parsed_conditions.any_of,
+            "any_of",
+            config_path,
+            |conditions| {
+                get_folder_conditions(conditions, config_path, parsed_config)
+            },
+        )?,
+        not: parsed_conditions
+            .not
+            .as_ref()
+            .map(|conditions| -> Result<Box<FolderConditions>, String> {
+                Ok(Box::new(get_folder_conditions(
+                    conditions,
+                    config_path,
+                    parsed_config,
+                )?))
+            })
+            .transpose()?,
+    })
+}
+
+fn get_depth_from_root(
+    config_path: &String,
+    depth_from: &Option<String>,
+) -> Result<bool, String> {
+    match depth_from.as_deref() {
+        None | Some("config_folder") => Ok(false),
+        Some("root") => Ok(true),
+        Some(depth_from) => Err(format!(
+            "Config error in '{}': Invalid 'depth_from' value '{}', use 'root' or 'config_folder'",
+            config_path, depth_from
+        )),
+    }
+}
+
+fn get_nested_conditions<P, T>(
+    parsed_conditions: &Option<Vec<P>>,
+    combinator: &str,
+    config_path: &String,
+    get_conditions: impl Fn(&P) -> Result<T, String>,
+) -> Result<Option<Vec<T>>, String> {
+    normalize_combinator_items(
+        parsed_conditions,
+        combinator,
+        "condition",
+        config_path,
+        get_conditions,
+    )
+}
+
+fn check_nested_expect_severity(
+    severity: &Option<String>,
+    config_path: &String,
+) -> Result<(), String> {
+    if severity.is_some() {
+        return Err(format!(
+            "Config error in '{}': 'severity' cannot be used inside 'all_of', 'any_of' or 'not'",
+            config_path
+        ));
+    }
+
+    Ok(())
+}
+
+/// The rules inside `one_of` are only used to find the matching one, the
+/// `error_msg` is always reported as an error
+fn check_one_of_rule_severity(
+    severity: Severity,
+    config_path: &String,
+) -> Result<(), String> {
+    if severity != Severity::Error {
+        return Err(format!(
+            "Config error in '{}': 'id' and 'severity' cannot be used in the rules inside 'one_of'",
+            config_path
+        ));
+    }
+
+    Ok(())
+}
+
+/// The expects of `one_of` and `expect_one_of` only select the matching rule,
+/// the severity of the `expect_one_of` rule is used for the `error_msg`
+fn check_one_of_expect_severity(
+    severity: &Option<Severity>,
+    config_path: &String,
+) -> Result<(), String> {
+    if severity.is_some() {
+        return Err(format!(
+            "Config error in '{}': 'severity' cannot be used in the expects of 'one_of' or 'expect_one_of'",
+            config_path
+        ));
+    }
+
+    Ok(())
+}
+
+fn get_nested_expects<P, T>(
+    parsed_expects: &Option<Vec<P>>,
+    combinator: &str,
+    config_path: &String,
+    get_expect: impl Fn(&P) -> Result<T, String>,
+) -> Result<Option<Vec<T>>, String> {
+    normalize_combinator_items(
+        parsed_expects,
+        combinator,
+        "expectation",
+        config_path,
+        get_expect,
+    )
+}
+
+fn normalize_combinator_items<P, T>(
+    parsed_items: &Option<Vec<P>>,
+    combinator: &str,
+    item_kind: &str,
+    config_path: &String,
+    normalize_item: impl Fn(&P) -> Result<T, String>,
+) -> Result<Option<Vec<T>>, String> {
+    let Some(parsed_items) = parsed_items else {
+        return Ok(None);
+    };
+
+    if parsed_items.is_empty() {
+        return Err(format!(
+            "Config error in '{}': '{}' must contain at least one {}",
+            config_path, combinator, item_kind
+        ));
+    }
+
+    parsed_items
+        .iter()
+        .map(normalize_item)
+        .collect::<Result<Vec<T>, String>>()
+        .map(Some)
+}
+
 fn normalize_parsed_match_import(
     parsed_match_import: Vec<ParsedMatchImport>,
 ) -> Vec<MatchImport> {
@@ -1087,28 +2237,70 @@ fn normalize_parsed_match_import(
         .collect::<Vec<MatchImport>>()
 }
 
+fn get_line_scope(
+    config_path: &String,
+    in_first_lines: &Option<usize>,
+    on_line: &Option<usize>,
+    in_range: &Option<(usize, usize)>,
+) -> Result<Option<LineScope>, String> {
+    let line_scopes = [
+        in_first_lines.map(|lines| (1, lines)),
+        on_line.map(|line| (line, line)),
+        *in_range,
+    ];
+
+    let mut configured_scopes = line_scopes.iter().flatten();
+
+    let (start, end) = match configured_scopes.next() {
+        Some(line_scope) => *line_scope,
+        None => return Ok(None),
+    };
+
+    if configured_scopes.next().is_some() {
+        return Err(format!(
+            "Config error in '{}': Use only one of 'in_first_lines', 'on_line' or 'in_range'",
+            config_path
+        ));
+    }
+
+    if start == 0 || end < start {
+        return Err(format!(
+            "Config error in '{}': Invalid lines scope, lines start at 1 and the 'in_range' end should not be before its start",
+            config_path
+        ));
+    }
+
+    Ok(Some(LineScope { start, end }))
+}
+
 fn normalize_content_matches(
     parsed_content_matches: Option<ParsedFileContentMatches>,
     config_path: &String,
-) -> Option<Vec<ContentMatches>> {
+) -> Result<Option<Vec<ContentMatches>>, String> {
     if let Some(content_matches) = parsed_content_matches {
         match content_matches {
-            ParsedFileContentMatches::Single(match_text) => Some(vec![ContentMatches {
-                at_least: 1,
-                at_most: None,
-                matches: Matches::All(vec![match_text]),
-            }]),
-            ParsedFileContentMatches::Multiple(items) => Some(
-                items
-                    .iter()
-                    .map(|item| -> ContentMatches {
-                        match item {
-                            ParsedFileContentMatchesItem::Single(match_text) => ContentMatches {
+            ParsedFileContentMatches::Single(match_text) => {
+                Ok(Some(vec![ContentMatches {
+                    at_least: 1,
+                    at_most: None,
+                    matches: Matches::All(vec![match_text]),
+                    lines: None,
+                }]))
+            }
+            ParsedFileContentMatches::Multiple(items) => items
+                .iter()
+                .map(|item| -> Result<ContentMatches, String> {
+                    match item {
+                        ParsedFileContentMatchesItem::Single(match_text) => {
+                            Ok(ContentMatches {
                                 at_least: 1,
                                 at_most: None,
                                 matches: Matches::All(vec![match_text.clone()]),
-                            },
-                            ParsedFileContentMatchesItem::Config(config) => ContentMatches {
+                                lines: None,
+                            })
+                        }
+                        ParsedFileContentMatchesItem::Config(config) => {
+                            Ok(ContentMatches {
                                 at_least: config.at_least.unwrap_or(1),
                                 at_most: config.at_most,
                                 matches: {
@@ -1120,17 +2312,24 @@ fn normalize_content_matches(
                                         Matches::All(vec![])
                                     }
                                 },
-                            },
-                            ParsedFileContentMatchesItem::Error(error) => {
-                                panic!(
-                                    "Config error in {}: Invalid content_matches: {:#?}",
-                                    config_path, error
-                                )
-                            }
+                                lines: get_line_scope(
+                                    config_path,
+                                    &config.in_first_lines,
+                                    &config.on_line,
+                                    &config.in_range,
+                                )?,
+                            })
                         }
-                    })
-                    .collect::<Vec<ContentMatches>>(),
-            ),
+                        ParsedFileContentMatchesItem::Error(error) => {
+                            panic!(
+                                "Config error in {}: Invalid content_matches: {:#?}",
+                                config_path, error
+                            )
+                        }
+                    }
+                })
+                .collect::<Result<Vec<ContentMatches>, String>>()
+                .map(Some),
             ParsedFileContentMatches::Error(error) => {
                 panic!(
                     "Config error in {}: Invalid content_matches: {:#?}",
@@ -1139,10 +2338,47 @@ fn normalize_content_matches(
             }
         }
     } else {
-        None
+        Ok(None)
     }
 }
 
+fn normalize_content_not_matches(
+    parsed_content_not_matches: &Option<
+        SingleOrMultiple<ParsedContentNotMatchesItem>,
+    >,
+    config_path: &String,
+) -> Result<Option<Vec<ContentNotMatches>>, String> {
+    normalize_single_or_multiple_option(parsed_content_not_matches)
+        .map(|items| {
+            items
+                .iter()
+                .map(|item| match item {
+                    ParsedContentNotMatchesItem::Pattern(pattern) => {
+                        Ok(ContentNotMatches {
+                            pattern: pattern.clone(),
+                            lines: None,
+                        })
+                    }
+                    ParsedContentNotMatchesItem::Config {
+                        pattern,
+                        in_first_lines,
+                        on_line,
+                        in_range,
+                    } => Ok(ContentNotMatches {
+                        pattern: pattern.clone(),
+                        lines: get_line_scope(
+                            config_path,
+                            in_first_lines,
+                            on_line,
+                            in_range,
+                        )?,
+                    }),
+                })
+                .collect()
+        })
+        .transpose()
+}
+
 fn check_rules_expects<T, B>(
     expect: &Option<T>,
     expect_one_of: &Option<B>,
@@ -1239,6 +2475,8 @@ pub fn normalize_folder_config(
                         not_touch: None,
                         ignore_in_config_tests: None,
                         is_warning: None,
+                        id: None,
+                        severity: None,
                     })
                     .collect();
 
@@ -1321,6 +2559,7 @@ pub fn normalize_folder_config(
                                     allow_unexpected: None,
                                     unexpected_files_error_msg: None,
                                     unexpected_folders_error_msg: None,
+                                    case_collisions: None,
                                 }),
                                 folder_path.clone(),
                                 normalize_blocks,
@@ -1360,6 +2599,7 @@ pub fn normalize_folder_config(
                     .unexpected_folders_error_msg
                     .clone(),
                 unexpected_error_msg: config.unexpected_error_msg.clone(),
+                case_collisions: config.case_collisions,
                 allow_unexpected_files: config
                     .allow_unexpected_files
                     .unwrap_or(default_allow_unexpected_files_or_folders),
@@ -1405,6 +2645,10 @@ pub fn get_config(parsed_config: &ParsedConfig) -> Result<Config, String> {
         ));
     }
 
+    for (name, pattern) in parsed_config.name_cases.iter().flatten() {
+        get_custom_name_case(name, pattern)?;
+    }
+
     let normalized_block = &normalize_blocks(&parsed_config.blocks)?;
 
     let mut analyze_content_of_files_types = parsed_config
@@ -1430,6 +2674,7 @@ pub fn get_config(parsed_config: &ParsedConfig) -> Result<Config, String> {
             "allow_warnings",
         )?,
         error_msg_vars: parsed_config.error_msg_vars.clone(),
+        acronyms: get_acronyms(parsed_config)?,
         root_folder: normalize_folder_config(
             &parsed_config.root_folder,
             String::from("."),
@@ -1443,14 +2688,103 @@ pub fn get_config(parsed_config: &ParsedConfig) -> Result<Config, String> {
             ]
             .concat(),
         ),
+        use_gitignore: get_true_flag(
+            &String::from("."),
+            &parsed_config.use_gitignore,
+            "use_gitignore",
+        )?,
+        dotfiles: get_dotfiles_policy(&parsed_config.dotfiles)?,
+        symlinks: get_symlinks_policy(&parsed_config.symlinks)?,
+        encoding: get_encoding(&parsed_config.encoding)?,
+        report_empty_folders: get_empty_folder_placeholders(
+            &String::from("."),
+            &parsed_config.report_empty_folders,
+            "report_empty_folders",
+        )?,
+        duplicate_content: get_duplicate_content(&parsed_config.duplicate_content)?,
         analyze_content_of_files_types,
         ts_config: parsed_config.ts.as_ref().map(|ts| TsConfig {
             aliases: ts.aliases.clone(),
             unused_exports_entry_points: ts.unused_exports_entry_points.clone(),
         }),
+        severity_overrides: HashMap::new(),
     })
 }
 
+fn collect_rules_ids(
+    folder_rules: &[FolderRule],
+    file_rules: &[FileRule],
+    rules_ids: &mut HashSet<String>,
+) {
+    rules_ids.extend(file_rules.iter().filter_map(|rule| rule.id.clone()));
+
+    for rule in folder_rules {
+        rules_ids.extend(rule.id.clone());
+
+        if let AnyNoneOr::Or(expects) = &rule.expect {
+            for (child_folder_rules, child_file_rules) in expects
+                .iter()
+                .filter_map(|expect| expect.child_rules.as_ref())
+            {
+                collect_rules_ids(child_folder_rules, child_file_rules, rules_ids);
+            }
+        }
+    }
+}
+
+fn collect_folder_config_rules_ids(
+    folder_config: &FolderConfig,
+    rules_ids: &mut HashSet<String>,
+) {
+    collect_rules_ids(
+        &folder_config.folder_rules,
+        &folder_config.file_rules,
+        rules_ids,
+    );
+
+    rules_ids.extend(
+        folder_config
+            .one_of_blocks
+            .file_blocks
+            .iter()
+            .filter_map(|one_of| one_of.id.clone()),
+    );
+    rules_ids.extend(
+        folder_config
+            .one_of_blocks
+            .folder_blocks
+            .iter()
+            .filter_map(|one_of| one_of.id.clone()),
+    );
+
+    for sub_folder_config in folder_config.sub_folders_config.values() {
+        collect_folder_config_rules_ids(sub_folder_config, rules_ids);
+    }
+}
+
+/// Applies the `--severity rule_id=level` overrides, they take precedence over
+/// the rule and expect severities
+pub fn set_severity_overrides(
+    config: &mut Config,
+    overrides: &[(String, Severity)],
+) -> Result<(), String> {
+    let mut rules_ids = HashSet::new();
+    collect_folder_config_rules_ids(&config.root_folder, &mut rules_ids);
+
+    for (rule_id, severity) in overrides {
+        if !rules_ids.contains(rule_id) {
+            return Err(format!(
+                "Rule id '{}' used in '--severity' not found in the config",
+                rule_id
+            ));
+        }
+
+        config.severity_overrides.insert(rule_id.clone(), *severity);
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use insta::assert_debug_snapshot;
@@ -1499,29 +2833,63 @@ mod tests {
                                             "svg",
                                         ],
                                     ),
+                                    has_suffix: None,
                                     has_name: None,
                                     not_has_name: None,
+                                    has_path: None,
+                                    not_has_path: None,
+                                    in_folder: None,
+                                    not_in_folder: None,
                                     has_content: None,
                                     has_any_content: None,
                                     not_has_content: None,
+                                    is_symlink: false,
+                                    is_larger_than: None,
+                                    is_binary: false,
+                                    all_of: None,
+                                    any_of: None,
+                                    not: None,
                                 },
                             ),
                             expect: Or(
                                 [
                                     FileExpect {
                                         name_case_is: Some(
-                                            Kebab,
+                                            [
+                                                Kebab,
+                                            ],
                                         ),
                                         extension_is: None,
+                                        suffix_is: None,
                                         have_sibling_file: None,
+                                        have_sibling_folder: None,
+                                        not_have_sibling_file: None,
+                                        not_have_sibling_folder: None,
+                                        have_companion_file: None,
                                         content_matches: None,
                                         content_matches_some: None,
                                         content_not_matches: None,
                                         name_is: None,
                                         name_is_not: None,
+                                        name_is_unique_in: None,
                                         ts: None,
                                         is_not_empty: false,
+                                        max_size: None,
+                                        max_lines: None,
+                                        min_lines: None,
+                                        encoding_is: None,
+                                        content_is_unique: false,
+                                        json_matches: None,
+                                        yaml_matches: None,
+                                        json_schema: None,
+                                        front_matter_has: None,
+                                        has_heading: None,
+                                        relative_links_resolve: false,
+                                        all_of: None,
+                                        any_of: None,
+                                        not: None,
                                         error_msg: None,
+                                        severity: None,
                                     },
                                 ],
                             ),
@@ -1529,7 +2897,8 @@ mod tests {
                             not_touch: false,
                             ignore_in_config_tests: false,
                             error_msg: None,
-                            is_warning: false,
+                            id: None,
+                            severity: Error,
                         },
                     ],
                     folder_rules: [
@@ -1541,7 +2910,8 @@ mod tests {
                             allow_unexpected_files: false,
                             allow_unexpected_folders: false,
                             error_msg: None,
-                            is_warning: false,
+                            id: None,
+                            severity: Error,
                         },
                     ],
                     optional: false,
@@ -1555,6 +2925,7 @@ mod tests {
                     unexpected_folders_error_msg: None,
                     unexpected_error_msg: None,
                     append_error_msg: None,
+                    case_collisions: None,
                 },
             },
             file_rules: [],
@@ -1570,6 +2941,7 @@ mod tests {
             unexpected_folders_error_msg: None,
             unexpected_error_msg: None,
             append_error_msg: None,
+            case_collisions: None,
         }
         "###
         );
@@ -1611,29 +2983,63 @@ mod tests {
                                                     "svg",
                                                 ],
                                             ),
+                                            has_suffix: None,
                                             has_name: None,
                                             not_has_name: None,
+                                            has_path: None,
+                                            not_has_path: None,
+                                            in_folder: None,
+                                            not_in_folder: None,
                                             has_content: None,
                                             has_any_content: None,
                                             not_has_content: None,
+                                            is_symlink: false,
+                                            is_larger_than: None,
+                                            is_binary: false,
+                                            all_of: None,
+                                            any_of: None,
+                                            not: None,
                                         },
                                     ),
                                     expect: Or(
                                         [
                                             FileExpect {
                                                 name_case_is: Some(
-                                                    Kebab,
+                                                    [
+                                                        Kebab,
+                                                    ],
                                                 ),
                                                 extension_is: None,
+                                                suffix_is: None,
                                                 have_sibling_file: None,
+                                                have_sibling_folder: None,
+                                                not_have_sibling_file: None,
+                                                not_have_sibling_folder: None,
+                                                have_companion_file: None,
                                                 content_matches: None,
                                                 content_matches_some: None,
                                                 content_not_matches: None,
                                                 name_is: None,
                                                 name_is_not: None,
+                                                name_is_unique_in: None,
                                                 ts: None,
                                                 is_not_empty: false,
+                                                max_size: None,
+                                                max_lines: None,
+                                                min_lines: None,
+                                                encoding_is: None,
+                                                content_is_unique: false,
+                                                json_matches: None,
+                                                yaml_matches: None,
+                                                json_schema: None,
+                                                front_matter_has: None,
+                                                has_heading: None,
+                                                relative_links_resolve: false,
+                                                all_of: None,
+                                                any_of: None,
+                                                not: None,
                                                 error_msg: None,
+                                                severity: None,
                                             },
                                         ],
                                     ),
@@ -1641,7 +3047,8 @@ mod tests {
                                     not_touch: false,
                                     ignore_in_config_tests: false,
                                     error_msg: None,
-                                    is_warning: false,
+                                    id: None,
+                                    severity: Error,
                                 },
                             ],
                             folder_rules: [
@@ -1653,7 +3060,8 @@ mod tests {
                                     allow_unexpected_files: false,
                                     allow_unexpected_folders: false,
                                     error_msg: None,
-                                    is_warning: false,
+                                    id: None,
+                                    severity: Error,
                                 },
                             ],
                             optional: false,
@@ -1667,6 +3075,7 @@ mod tests {
                             unexpected_folders_error_msg: None,
                             unexpected_error_msg: None,
                             append_error_msg: None,
+                            case_collisions: None,
                         },
                     },
                     file_rules: [],
@@ -1682,6 +3091,7 @@ mod tests {
                     unexpected_folders_error_msg: None,
                     unexpected_error_msg: None,
                     append_error_msg: None,
+                    case_collisions: None,
                 },
             },
             file_rules: [],
@@ -1697,6 +3107,7 @@ mod tests {
             unexpected_folders_error_msg: None,
             unexpected_error_msg: None,
             append_error_msg: None,
+            case_collisions: None,
         }
         "###
         );
@@ -1,20 +1,171 @@
+use encoding_rs::Encoding;
 use globset::{Glob, GlobSet};
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use lazy_static::lazy_static;
 use rayon::prelude::*;
 use std::{
     collections::HashMap,
-    fs::read_to_string,
+    fs::{read, File as FsFile},
+    io::{BufRead, BufReader},
     path::{Path, PathBuf},
+    sync::{Arc, Mutex},
 };
+use xxhash_rust::xxh3::Xxh3;
 
-use crate::internal_config::Config;
+use crate::{
+    content_cache::ContentCache,
+    internal_config::{
+        AnyNoneOr, AnyOr, Config, DotfilesPolicy, FileConditions, FileExpect,
+        FileRule, FolderConfig, FolderExpect, FolderRule, SymlinksPolicy,
+    },
+};
+
+const MAX_CACHED_CONTENTS_SIZE: usize = 64 * 1024 * 1024;
+
+/// Files with a null byte in the first bytes are considered binary, like git
+/// does
+const BINARY_DETECTION_SIZE: usize = 8000;
+
+lazy_static! {
+    static ref FILE_CONTENTS_CACHE: Mutex<ContentCache> =
+        Mutex::new(ContentCache::new(MAX_CACHED_CONTENTS_SIZE));
+}
 
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct File {
     pub basename: String,
     pub name_with_ext: String,
     pub content: Option<String>,
+    /// Path used to read the content on demand when it is not kept in memory
+    pub content_path: Option<PathBuf>,
+    /// Configured `encoding` of the content, the content that is not valid
+    /// UTF-8 is decoded lossily if it is not set
+    pub encoding: Option<&'static Encoding>,
+    /// Path of the loaded file, used to compare the files with the same
+    /// content hash byte by byte
+    pub path: Option<PathBuf>,
     pub extension: Option<String>,
     pub relative_path: String,
+    pub is_symlink: bool,
+    /// Size in bytes
+    pub size: u64,
+    pub line_count: usize,
+    pub is_binary: bool,
+    pub is_utf8: bool,
+    /// Hash of the content, only set for the files checked for duplicates
+    pub content_hash: Option<u64>,
+}
+
+impl File {
+    /// Returns the content of the files listed in
+    /// `analyze_content_of_files_types`, it is read only when needed and just
+    /// the most recently used contents are kept in memory
+    pub fn get_content(&self) -> Result<Option<Arc<str>>, String> {
+        match self.get_text_content()? {
+            None if self.is_binary || self.content_path.is_some() => {
+                Err("file is binary, its content can't be analyzed".to_string())
+            }
+            content => Ok(content),
+        }
+    }
+
+    /// Same as `get_content`, but binary files have no content instead of
+    /// returning an error
+    pub fn get_text_content(&self) -> Result<Option<Arc<str>>, String> {
+        if self.is_binary {
+            return Ok(None);
+        }
+
+        if let Some(content) = &self.content {
+            return Ok(Some(Arc::from(content.as_str())));
+        }
+
+        let path = match &self.content_path {
+            Some(path) => path,
+            None => return Ok(None),
+        };
+
+        let cache_key = path_to_string(path);
+
+        if let Some(content) = FILE_CONTENTS_CACHE.lock().unwrap().get(&cache_key) {
+            return Ok(Some(content));
+        }
+
+        let bytes = read(path).map_err(|err| {
+            format!("Error reading file '{}': {}", path.display(), err)
+        })?;
+
+        // the files are only scanned for binary content if a rule needs it
+        if is_binary_content(&bytes) {
+            return Ok(None);
+        }
+
+        let content: Arc<str> = decode_content(bytes, self.encoding).into();
+
+        FILE_CONTENTS_CACHE
+            .lock()
+            .unwrap()
+            .insert(cache_key, content.clone());
+
+        Ok(Some(content))
+    }
+
+    /// Raw content of the file, used to compare files byte by byte
+    pub fn get_bytes(&self) -> Result<Vec<u8>, String> {
+        if let Some(content) = &self.content {
+            return Ok(content.as_bytes().to_vec());
+        }
+
+        match &self.path {
+            Some(path) => read(path).map_err(|err| {
+                format!("Error reading file '{}': {}", path.display(), err)
+            }),
+            None => Ok(vec![]),
+        }
+    }
+
+    /// The first segment of the file name, e.g. `Button` in `Button.test.tsx`
+    pub fn name(&self) -> &str {
+        self.name_segments().0
+    }
+
+    /// The segments between the name and the extension, e.g. `["test"]` in
+    /// `Button.test.tsx` or `["d"]` in `index.d.ts`
+    pub fn suffixes(&self) -> Vec<&str> {
+        self.name_segments().1
+    }
+
+    fn name_segments(&self) -> (&str, Vec<&str>) {
+        // the leading dot of dotfiles like `.eslintrc.js` is part of the name
+        let name_start = if self.basename.starts_with('.') { 1 } else { 0 };
+
+        match self.basename[name_start..].find('.') {
+            Some(index) => {
+                let (name, suffixes) = self.basename.split_at(name_start + index);
+
+                (name, suffixes[1..].split('.').collect())
+            }
+            None => (&self.basename, vec![]),
+        }
+    }
+}
+
+/// Decodes the files that are not valid UTF-8 with the configured `encoding`
+/// or lossily if it is not set
+fn decode_content(bytes: Vec<u8>, encoding: Option<&'static Encoding>) -> String {
+    match String::from_utf8(bytes) {
+        Ok(content) => content,
+        Err(err) => {
+            let bytes = err.into_bytes();
+
+            match encoding {
+                Some(encoding) => {
+                    encoding.decode_without_bom_handling(&bytes).0.into_owned()
+                }
+                None => String::from_utf8_lossy(&bytes).into_owned(),
+            }
+        }
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -27,6 +178,7 @@ pub enum FolderChild {
 pub struct Folder {
     pub name: String,
     pub children: Vec<FolderChild>,
+    pub is_symlink: bool,
 }
 
 fn path_to_string(path: &Path) -> String {
@@ -49,23 +201,125 @@ fn file_stem_to_string(path: &Path) -> Result<String, String> {
         })
 }
 
+/// Options computed once from the config and shared by all the loaded
+/// folders
+struct LoadOptions {
+    ignore_paths_set: GlobSet,
+    /// Lines and encodings are only scanned if a rule uses them, otherwise
+    /// just the files with content rules are read
+    scan_files: bool,
+    /// Symlinks to targets outside the root are not followed
+    canonical_root: PathBuf,
+}
+
 pub fn load_folder_structure(
     path: &Path,
     config: &Config,
     root: &PathBuf,
     is_root: bool,
 ) -> Result<Folder, String> {
-    let ignore_paths_set = build_ignore_paths_set(config, is_root)?;
+    let options = LoadOptions {
+        ignore_paths_set: build_ignore_paths_set(config, is_root)?,
+        scan_files: folder_config_needs_scan(&config.root_folder),
+        canonical_root: root.canonicalize().map_err(|err| {
+            format!("Error resolving directory '{}': {}", root.display(), err)
+        })?,
+    };
+
+    FILE_CONTENTS_CACHE.lock().unwrap().clear();
 
     load_folder_structure_with_ignores(
         path,
         config,
         root,
         is_root,
-        &ignore_paths_set,
+        &options,
+        &[],
+        &[],
     )
 }
 
+fn folder_config_needs_scan(folder_config: &FolderConfig) -> bool {
+    rules_need_scan(&folder_config.folder_rules, &folder_config.file_rules)
+        || folder_config
+            .one_of_blocks
+            .file_blocks
+            .iter()
+            .any(|block| rules_need_scan(&[], &block.rules))
+        || folder_config
+            .one_of_blocks
+            .folder_blocks
+            .iter()
+            .any(|block| rules_need_scan(&block.rules, &[]))
+        || folder_config
+            .sub_folders_config
+            .values()
+            .any(folder_config_needs_scan)
+}
+
+fn rules_need_scan(folder_rules: &[FolderRule], file_rules: &[FileRule]) -> bool {
+    let file_rule_needs_scan = |rule: &FileRule| {
+        let conditions_need_scan = match &rule.conditions {
+            AnyOr::Any => false,
+            AnyOr::Or(conditions) => file_conditions_need_scan(conditions),
+        };
+
+        conditions_need_scan
+            || match &rule.expect {
+                AnyNoneOr::Or(expects) => expects.iter().any(file_expect_needs_scan),
+                _ => false,
+            }
+    };
+
+    let folder_rule_needs_scan = |rule: &FolderRule| match &rule.expect {
+        AnyNoneOr::Or(expects) => expects.iter().any(folder_expect_needs_scan),
+        _ => false,
+    };
+
+    file_rules.iter().any(file_rule_needs_scan)
+        || folder_rules.iter().any(folder_rule_needs_scan)
+}
+
+fn file_conditions_need_scan(conditions: &FileConditions) -> bool {
+    conditions.is_binary
+        || [&conditions.all_of, &conditions.any_of]
+            .into_iter()
+            .flatten()
+            .flatten()
+            .any(file_conditions_need_scan)
+        || conditions
+            .not
+            .as_deref()
+            .is_some_and(file_conditions_need_scan)
+}
+
+fn file_expect_needs_scan(expect: &FileExpect) -> bool {
+    expect.max_lines.is_some()
+        || expect.min_lines.is_some()
+        || expect.encoding_is.is_some()
+        || [&expect.all_of, &expect.any_of]
+            .into_iter()
+            .flatten()
+            .flatten()
+            .any(file_expect_needs_scan)
+        || expect.not.as_deref().is_some_and(file_expect_needs_scan)
+}
+
+fn folder_expect_needs_scan(expect: &FolderExpect) -> bool {
+    expect
+        .child_rules
+        .as_ref()
+        .is_some_and(|(folder_rules, file_rules)| {
+            rules_need_scan(folder_rules, file_rules)
+        })
+        || [&expect.all_of, &expect.any_of]
+            .into_iter()
+            .flatten()
+            .flatten()
+            .any(folder_expect_needs_scan)
+        || expect.not.as_deref().is_some_and(folder_expect_needs_scan)
+}
+
 fn build_ignore_paths_set(
     config: &Config,
     is_root: bool,
@@ -92,13 +346,101 @@ fn build_ignore_paths_set(
         .map_err(|err| format!("Error building ignore patterns: {}", err))
 }
 
+type IgnoreFiles = Vec<Arc<Gitignore>>;
+
+/// Returns the parent ignore files plus the ones declared in the folder, the
+/// last ones have precedence like in nested `.gitignore` files
+fn get_folder_ignore_files(
+    path: &Path,
+    config: &Config,
+    is_root: bool,
+    parent_ignore_files: &[Arc<Gitignore>],
+) -> Result<IgnoreFiles, String> {
+    let mut ignore_files = parent_ignore_files.to_vec();
+    let mut ignore_file_paths = vec![];
+
+    if config.use_gitignore {
+        if is_root {
+            ignore_file_paths.push(path.join(".git").join("info").join("exclude"));
+        }
+
+        ignore_file_paths.push(path.join(".gitignore"));
+    }
+
+    ignore_file_paths.push(path.join(".palinterignore"));
+
+    let mut builder = GitignoreBuilder::new(path);
+    let mut has_ignore_file = false;
+
+    for ignore_file_path in ignore_file_paths {
+        if !ignore_file_path.is_file() {
+            continue;
+        }
+
+        if let Some(err) = builder.add(&ignore_file_path) {
+            return Err(format!(
+                "Error reading ignore file '{}': {}",
+                ignore_file_path.display(),
+                err
+            ));
+        }
+
+        has_ignore_file = true;
+    }
+
+    if has_ignore_file {
+        ignore_files.push(Arc::new(builder.build().map_err(|err| {
+            format!(
+                "Error building ignore files of '{}': {}",
+                path.display(),
+                err
+            )
+        })?));
+    }
+
+    Ok(ignore_files)
+}
+
+fn is_ignored_by_ignore_files(
+    path: &Path,
+    is_dir: bool,
+    ignore_files: &[Arc<Gitignore>],
+) -> bool {
+    for ignore_file in ignore_files.iter().rev() {
+        let matched = ignore_file.matched(path, is_dir);
+
+        if matched.is_ignore() {
+            return true;
+        }
+
+        if matched.is_whitelist() {
+            return false;
+        }
+    }
+
+    false
+}
+
 fn load_folder_structure_with_ignores(
     path: &Path,
     config: &Config,
     root: &PathBuf,
     is_root: bool,
-    ignore_paths_set: &GlobSet,
+    options: &LoadOptions,
+    parent_ignore_files: &[Arc<Gitignore>],
+    parent_dirs: &[PathBuf],
 ) -> Result<Folder, String> {
+    let ignore_files =
+        get_folder_ignore_files(path, config, is_root, parent_ignore_files)?;
+
+    let dirs = [
+        parent_dirs.to_vec(),
+        vec![path.canonicalize().map_err(|err| {
+            format!("Error resolving directory '{}': {}", path.display(), err)
+        })?],
+    ]
+    .concat();
+
     let mut child_paths = path
         .read_dir()
         .map_err(|err| {
@@ -124,7 +466,15 @@ fn load_folder_structure_with_ignores(
     let children = child_paths
         .into_par_iter()
         .map(|child_path| {
-            load_folder_child(child_path, config, root, is_root, ignore_paths_set)
+            load_folder_child(
+                child_path,
+                config,
+                root,
+                is_root,
+                options,
+                &ignore_files,
+                &dirs,
+            )
         })
         .collect::<Result<Vec<_>, _>>()?
         .into_iter()
@@ -147,6 +497,7 @@ fn load_folder_structure_with_ignores(
     Ok(Folder {
         name: folder_name,
         children,
+        is_symlink: false,
     })
 }
 
@@ -155,7 +506,9 @@ fn load_folder_child(
     config: &Config,
     root: &PathBuf,
     is_root: bool,
-    ignore_paths_set: &GlobSet,
+    options: &LoadOptions,
+    ignore_files: &[Arc<Gitignore>],
+    parent_dirs: &[PathBuf],
 ) -> Result<Option<FolderChild>, String> {
     let relative_path = path.strip_prefix(root).map_err(|err| {
         format!(
@@ -166,34 +519,130 @@ fn load_folder_child(
         )
     })?;
 
-    if ignore_paths_set.is_match(relative_path) {
+    if options.ignore_paths_set.is_match(relative_path) {
+        return Ok(None);
+    }
+
+    let is_symlink = path
+        .symlink_metadata()
+        .map_err(|err| {
+            format!("Error reading metadata of '{}': {}", path.display(), err)
+        })?
+        .file_type()
+        .is_symlink();
+
+    if is_symlink && config.symlinks == SymlinksPolicy::Skip {
+        return Ok(None);
+    }
+
+    if is_symlink && config.symlinks != SymlinksPolicy::Report {
+        // broken links have no target to follow, they are only reported with
+        // the `report` policy
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let target = path.canonicalize().map_err(|err| {
+            format!("Error resolving symlink '{}': {}", path.display(), err)
+        })?;
+
+        // following links outside the root would check files that are not
+        // part of the project
+        if !target.starts_with(&options.canonical_root) {
+            return Ok(None);
+        }
+
+        // the link points to a folder that is being loaded, following it
+        // would recurse forever
+        if parent_dirs.contains(&target) {
+            return Ok(None);
+        }
+    }
+
+    let is_dir = path.is_dir();
+
+    if is_ignored_by_ignore_files(&path, is_dir, ignore_files) {
         return Ok(None);
     }
 
-    if path.is_dir() {
+    if config.dotfiles == DotfilesPolicy::Exclude
+        && file_name_to_string(&path)?.starts_with('.')
+    {
+        return Ok(None);
+    }
+
+    if is_dir {
         if should_skip_root_dir(&path, config, is_root)? {
             return Ok(None);
         }
 
+        if is_symlink && config.symlinks == SymlinksPolicy::Report {
+            return Ok(Some(FolderChild::Folder(Folder {
+                name: file_name_to_string(&path)?,
+                children: vec![],
+                is_symlink,
+            })));
+        }
+
         return load_folder_structure_with_ignores(
             &path,
             config,
             root,
             false,
-            ignore_paths_set,
+            options,
+            ignore_files,
+            parent_dirs,
         )
-        .map(FolderChild::Folder)
-        .map(Some);
+        .map(|folder| {
+            Some(FolderChild::Folder(Folder {
+                is_symlink,
+                ..folder
+            }))
+        });
     }
 
     let extension = path.extension().map(|s| s.to_string_lossy().into_owned());
 
+    let (content_path, size, scan) = if is_symlink
+        && config.symlinks == SymlinksPolicy::Report
+    {
+        (None, 0, FileScan::default())
+    } else {
+        let size = path
+            .metadata()
+            .map_err(|err| {
+                format!("Error reading metadata of '{}': {}", path.display(), err)
+            })?
+            .len();
+
+        let hash_content = should_hash_content(config, &extension, size);
+
+        (
+            get_content_path(config, &extension, &path),
+            size,
+            if options.scan_files || hash_content {
+                scan_file(&path, hash_content)?
+            } else {
+                FileScan::default()
+            },
+        )
+    };
+
     Ok(Some(FolderChild::FileChild(File {
         basename: file_stem_to_string(&path)?,
         name_with_ext: file_name_to_string(&path)?,
-        content: get_file_content(config, &extension, path.clone())?,
+        content: None,
+        content_path,
+        encoding: config.encoding,
+        path: Some(path.clone()),
         extension,
         relative_path: format!("./{}", path_to_string(relative_path)),
+        is_symlink,
+        size,
+        line_count: scan.line_count,
+        is_binary: scan.is_binary,
+        is_utf8: scan.is_utf8,
+        content_hash: scan.content_hash,
     })))
 }
 
@@ -210,20 +659,134 @@ fn should_skip_root_dir(
             .contains_key(&format!("/{}", file_name_to_string(path)?)))
 }
 
-fn get_file_content(
+fn get_content_path(
     config: &Config,
     extension: &Option<String>,
-    path: PathBuf,
-) -> Result<Option<String>, String> {
-    if let Some(extension) = extension {
-        if config.analyze_content_of_files_types.contains(extension) {
-            return read_to_string(&path).map(Some).map_err(|err| {
-                format!("Error reading file '{}': {}", path.display(), err)
-            });
+    path: &Path,
+) -> Option<PathBuf> {
+    extension
+        .as_ref()
+        .filter(|extension| {
+            config.analyze_content_of_files_types.contains(extension)
+        })
+        .map(|_| path.to_path_buf())
+}
+
+/// Empty files are not hashed, they would all be reported as duplicates
+fn should_hash_content(
+    config: &Config,
+    extension: &Option<String>,
+    size: u64,
+) -> bool {
+    config
+        .duplicate_content
+        .as_ref()
+        .is_some_and(|duplicate_content| {
+            size > 0
+                && (extension.as_ref().is_some_and(|extension| {
+                    config.analyze_content_of_files_types.contains(extension)
+                }) || duplicate_content
+                    .min_size
+                    .is_some_and(|min_size| size >= min_size))
+        })
+}
+
+pub fn count_lines(content: &str) -> usize {
+    content.lines().count()
+}
+
+fn is_binary_content(bytes: &[u8]) -> bool {
+    bytes[..bytes.len().min(BINARY_DETECTION_SIZE)].contains(&0)
+}
+
+#[derive(Debug)]
+pub struct FileScan {
+    pub line_count: usize,
+    pub is_binary: bool,
+    pub is_utf8: bool,
+    pub content_hash: Option<u64>,
+}
+
+/// The files that are not scanned are considered text files
+impl Default for FileScan {
+    fn default() -> Self {
+        FileScan {
+            line_count: 0,
+            is_binary: false,
+            is_utf8: true,
+            content_hash: None,
+        }
+    }
+}
+
+/// Counts the lines and detects binary or non UTF-8 content without keeping
+/// the content of the file in memory, the content is also hashed if
+/// `hash_content` is true
+pub fn scan_file(path: &Path, hash_content: bool) -> Result<FileScan, String> {
+    let file = FsFile::open(path).map_err(|err| {
+        format!("Error reading file '{}': {}", path.display(), err)
+    })?;
+
+    let mut reader = BufReader::new(file);
+    let mut scan = FileScan::default();
+    let mut hasher = hash_content.then(Xxh3::new);
+    let mut scanned_size = 0;
+    let mut last_byte = None;
+    // bytes of a char split between two chunks
+    let mut incomplete_char: Vec<u8> = vec![];
+
+    loop {
+        let chunk = reader.fill_buf().map_err(|err| {
+            format!("Error reading file '{}': {}", path.display(), err)
+        })?;
+
+        if chunk.is_empty() {
+            break;
         }
+
+        scan.line_count += chunk.iter().filter(|byte| **byte == b'\n').count();
+
+        if scanned_size < BINARY_DETECTION_SIZE {
+            let end = chunk.len().min(BINARY_DETECTION_SIZE - scanned_size);
+
+            scan.is_binary |= chunk[..end].contains(&0);
+        }
+
+        if scan.is_utf8 {
+            let bytes = [incomplete_char.as_slice(), chunk].concat();
+
+            match std::str::from_utf8(&bytes) {
+                Ok(_) => incomplete_char.clear(),
+                Err(err) if err.error_len().is_none() => {
+                    incomplete_char = bytes[err.valid_up_to()..].to_vec();
+                }
+                Err(_) => scan.is_utf8 = false,
+            }
+        }
+
+        if let Some(hasher) = &mut hasher {
+            hasher.update(chunk);
+        }
+
+        let chunk_size = chunk.len();
+
+        scanned_size += chunk_size;
+        last_byte = chunk.last().copied();
+        reader.consume(chunk_size);
+    }
+
+    if !incomplete_char.is_empty() {
+        scan.is_utf8 = false;
+    }
+
+    scan.content_hash = hasher.map(|hasher| hasher.digest());
+
+    // the last line may not end with a line break
+    if last_byte.is_some_and(|byte| byte != b'\n') {
+        scan.line_count += 1;
     }
 
-    Ok(None)
+    Ok(scan)
 }
 
 pub fn get_flattened_files_structure(folder: &Folder) -> HashMap<String, File> {
@@ -259,8 +822,12 @@ mod tests {
     use std::collections::{HashMap, HashSet};
 
     use insta::assert_debug_snapshot;
+    use xxhash_rust::xxh3::xxh3_64;
 
-    use crate::internal_config::{FolderConfig, FolderRule, OneOfBlocks};
+    use crate::internal_config::{
+        Acronyms, DotfilesPolicy, FolderConfig, FolderRule, OneOfBlocks, Severity,
+        SymlinksPolicy,
+    };
 
     use super::*;
 
@@ -286,18 +853,28 @@ mod tests {
                     not_touch: false,
                     allow_unexpected_files: false,
                     allow_unexpected_folders: false,
-                    is_warning: false,
+                    id: None,
+                    severity: Severity::Error,
                 }],
                 unexpected_files_error_msg: None,
                 unexpected_folders_error_msg: None,
                 unexpected_error_msg: None,
                 append_error_msg: None,
+                case_collisions: None,
                 one_of_blocks: OneOfBlocks::default(),
                 optional: false,
                 sub_folders_config: HashMap::new(),
             },
+            use_gitignore: false,
+            dotfiles: DotfilesPolicy::default(),
+            symlinks: SymlinksPolicy::default(),
+            encoding: None,
+            report_empty_folders: None,
+            duplicate_content: None,
             ts_config: None,
             error_msg_vars: None,
+            acronyms: Acronyms::default(),
+            severity_overrides: HashMap::new(),
         };
 
         let root = PathBuf::from("./src/fixtures/ignore_folder");
@@ -325,18 +902,28 @@ mod tests {
                     not_touch: false,
                     allow_unexpected_files: false,
                     allow_unexpected_folders: false,
-                    is_warning: false,
+                    id: None,
+                    severity: Severity::Error,
                 }],
                 unexpected_files_error_msg: None,
                 unexpected_folders_error_msg: None,
                 unexpected_error_msg: None,
                 append_error_msg: None,
+                case_collisions: None,
                 one_of_blocks: OneOfBlocks::default(),
                 optional: false,
                 sub_folders_config: HashMap::new(),
             },
+            use_gitignore: false,
+            dotfiles: DotfilesPolicy::default(),
+            symlinks: SymlinksPolicy::default(),
+            encoding: None,
+            report_empty_folders: None,
+            duplicate_content: None,
             ts_config: None,
             error_msg_vars: None,
+            acronyms: Acronyms::default(),
+            severity_overrides: HashMap::new(),
         };
 
         let root = PathBuf::from("./src/fixtures/ignore_folder");
@@ -369,18 +956,28 @@ mod tests {
                     not_touch: false,
                     allow_unexpected_files: false,
                     allow_unexpected_folders: false,
-                    is_warning: false,
+                    id: None,
+                    severity: Severity::Error,
                 }],
                 unexpected_files_error_msg: None,
                 unexpected_folders_error_msg: None,
                 append_error_msg: None,
+                case_collisions: None,
                 unexpected_error_msg: None,
                 one_of_blocks: OneOfBlocks::default(),
                 optional: false,
                 sub_folders_config: HashMap::new(),
             },
+            use_gitignore: false,
+            dotfiles: DotfilesPolicy::default(),
+            symlinks: SymlinksPolicy::default(),
+            encoding: None,
+            report_empty_folders: None,
+            duplicate_content: None,
             ts_config: None,
             error_msg_vars: None,
+            acronyms: Acronyms::default(),
+            severity_overrides: HashMap::new(),
         };
 
         let root = PathBuf::from("./src/fixtures/analyze_file_contents");
@@ -402,6 +999,7 @@ mod tests {
                 file_rules: vec![],
                 folder_rules: vec![],
                 append_error_msg: None,
+                case_collisions: None,
                 unexpected_error_msg: None,
                 unexpected_files_error_msg: None,
                 unexpected_folders_error_msg: None,
@@ -409,8 +1007,16 @@ mod tests {
                 optional: false,
                 sub_folders_config: HashMap::new(),
             },
+            use_gitignore: false,
+            dotfiles: DotfilesPolicy::default(),
+            symlinks: SymlinksPolicy::default(),
+            encoding: None,
+            report_empty_folders: None,
+            duplicate_content: None,
             ts_config: None,
             error_msg_vars: None,
+            acronyms: Acronyms::default(),
+            severity_overrides: HashMap::new(),
         };
 
         let root = PathBuf::from("./src/fixtures/analyze_file_contents");
@@ -419,4 +1025,253 @@ mod tests {
 
         assert_debug_snapshot!(folder);
     }
+
+    #[test]
+    fn scan_file_content() {
+        let root = std::env::temp_dir().join("palinter_scan_file_content");
+
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(&root).unwrap();
+
+        let scan = |content: &[u8]| {
+            let path = root.join("file");
+            std::fs::write(&path, content).unwrap();
+            let scan = scan_file(&path, false).unwrap();
+
+            (scan.line_count, scan.is_binary, scan.is_utf8)
+        };
+
+        assert_eq!(scan(b""), (0, false, true));
+        assert_eq!(scan(b"a\nb"), (2, false, true));
+        assert_eq!(scan(b"a\nb\n"), (2, false, true));
+        assert_eq!(scan(b"caf\xe9"), (1, false, false));
+        assert_eq!(scan(b"\x89PNG\0\0"), (1, true, false));
+
+        // a char split between the chunks of the reader is still valid
+        let mut content = "a".repeat(8191).into_bytes();
+        content.extend("é\n".as_bytes());
+        assert_eq!(scan(&content), (1, false, true));
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn hash_file_content() {
+        let root = std::env::temp_dir().join("palinter_hash_file_content");
+
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(&root).unwrap();
+
+        let hash = |content: &[u8]| {
+            let path = root.join("file");
+            std::fs::write(&path, content).unwrap();
+
+            scan_file(&path, true).unwrap().content_hash
+        };
+
+        // the hash doesn't depend on how the content is split in chunks
+        let content = "a".repeat(20000).into_bytes();
+        assert_eq!(hash(&content), Some(xxh3_64(&content)));
+        assert_ne!(hash(b"a"), hash(b"b"));
+
+        std::fs::remove_dir_all(&root).unwrap();
+
+        for (path, content) in [
+            ("index.ts", "export {};\n"),
+            ("empty.ts", ""),
+            ("logo.png", "small"),
+            ("banner.png", "larger than min size"),
+        ] {
+            std::fs::create_dir_all(&root).unwrap();
+            std::fs::write(root.join(path), content).unwrap();
+        }
+
+        let load = |config_yaml: &str| {
+            let parsed_config = crate::parse_config_file::parse_config_string(
+                &config_yaml.to_string(),
+                crate::parse_config_file::ParseFrom::Yaml,
+            )
+            .unwrap();
+
+            let config = crate::internal_config::get_config(&parsed_config).unwrap();
+
+            let folder = load_folder_structure(&root, &config, &root, true).unwrap();
+
+            let mut hashes: Vec<(String, Option<u64>)> =
+                get_flattened_files_structure(&folder)
+                    .into_values()
+                    .map(|file| (file.name_with_ext, file.content_hash))
+                    .collect();
+
+            hashes.sort();
+
+            hashes
+        };
+
+        // the files are only hashed when `duplicate_content` is configured
+        assert_eq!(
+            load(
+                r#"
+                analyze_content_of_files_types: [ts]
+                ./:
+                  rules:
+                    - if_file: any
+                      expect: any
+                "#
+            ),
+            vec![
+                ("banner.png".to_string(), None),
+                ("empty.ts".to_string(), None),
+                ("index.ts".to_string(), None),
+                ("logo.png".to_string(), None),
+            ]
+        );
+
+        // empty files are not hashed and `min_size` adds the files of any type
+        assert_eq!(
+            load(
+                r#"
+                analyze_content_of_files_types: [ts]
+                duplicate_content:
+                  min_size: 10b
+                ./:
+                  rules:
+                    - if_file: any
+                      expect: any
+                "#
+            ),
+            vec![
+                (
+                    "banner.png".to_string(),
+                    Some(xxh3_64(b"larger than min size"))
+                ),
+                ("empty.ts".to_string(), None),
+                ("index.ts".to_string(), Some(xxh3_64(b"export {};\n"))),
+                ("logo.png".to_string(), None),
+            ]
+        );
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn file_name_segments() {
+        let file = |basename: &str| File {
+            basename: basename.to_string(),
+            ..Default::default()
+        };
+
+        assert_eq!(file("Button").name(), "Button");
+        assert!(file("Button").suffixes().is_empty());
+
+        assert_eq!(file("Button.test").name(), "Button");
+        assert_eq!(file("Button.test").suffixes(), vec!["test"]);
+
+        assert_eq!(file("index.d").name(), "index");
+        assert_eq!(file("index.d").suffixes(), vec!["d"]);
+
+        assert_eq!(file("vite.config.e2e").suffixes(), vec!["config", "e2e"]);
+
+        assert_eq!(file(".eslintrc").name(), ".eslintrc");
+        assert!(file(".eslintrc").suffixes().is_empty());
+    }
+
+    fn load_ignore_files_fixture(name: &str, config_yaml: &str) -> Vec<String> {
+        let root = std::env::temp_dir().join(format!("palinter_{}", name));
+
+        let _ = std::fs::remove_dir_all(&root);
+
+        for (path, content) in [
+            (".gitignore", "dist\n*.log\n!keep.log\n"),
+            (".palinterignore", "generated/\n"),
+            (".git/info/exclude", "secret.txt\n"),
+            (".env", ""),
+            ("dist/index.js", ""),
+            ("error.log", ""),
+            ("keep.log", ""),
+            ("secret.txt", ""),
+            ("generated/types.ts", ""),
+            ("src/index.ts", ""),
+            ("src/.palinterignore", "*.snap\n!keep.snap\n"),
+            ("src/button.snap", ""),
+            ("src/keep.snap", ""),
+            ("src/nested/.gitignore", "!*.log\n"),
+            ("src/nested/debug.log", ""),
+        ] {
+            let file_path = root.join(path);
+            std::fs::create_dir_all(file_path.parent().unwrap()).unwrap();
+            std::fs::write(file_path, content).unwrap();
+        }
+
+        let parsed_config = crate::parse_config_file::parse_config_string(
+            &config_yaml.to_string(),
+            crate::parse_config_file::ParseFrom::Yaml,
+        )
+        .unwrap();
+
+        let config = crate::internal_config::get_config(&parsed_config).unwrap();
+
+        let folder = load_folder_structure(&root, &config, &root, true).unwrap();
+
+        std::fs::remove_dir_all(&root).unwrap();
+
+        let mut files: Vec<String> =
+            get_flattened_files_structure(&folder).into_keys().collect();
+
+        files.sort();
+
+        files
+    }
+
+    #[test]
+    fn respect_ignore_files() {
+        assert_eq!(
+            load_ignore_files_fixture(
+                "respect_ignore_files",
+                "
+                use_gitignore: true
+                ./:
+                  rules:
+                    - if_folder: any
+                      expect: any
+                "
+            ),
+            vec![
+                "./.env",
+                "./.gitignore",
+                "./.palinterignore",
+                "./keep.log",
+                "./src/.palinterignore",
+                "./src/index.ts",
+                "./src/keep.snap",
+                "./src/nested/.gitignore",
+                "./src/nested/debug.log",
+            ]
+        );
+    }
+
+    #[test]
+    fn respect_only_palinterignore_without_use_gitignore() {
+        assert_eq!(
+            load_ignore_files_fixture(
+                "respect_only_palinterignore",
+                "
+                dotfiles: exclude
+                ./:
+                  rules:
+                    - if_folder: any
+                      expect: any
+                "
+            ),
+            vec![
+                "./dist/index.js",
+                "./error.log",
+                "./keep.log",
+                "./secret.txt",
+                "./src/index.ts",
+                "./src/keep.snap",
+                "./src/nested/debug.log",
+            ]
+        );
+    }
 }
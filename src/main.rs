@@ -1,6 +1,7 @@
 mod analyze_ts_deps;
 mod check_folders;
 mod cli;
+mod content_cache;
 mod internal_config;
 mod load_folder_structure;
 mod parse_config_file;
@@ -14,7 +15,7 @@ use analyze_ts_deps::circular_deps::get_detailed_file_circular_deps_result;
 use check_folders::{check_root_folder, Problems};
 
 use cli::{get_cli_args, CliCommand};
-use internal_config::{get_config, Config};
+use internal_config::{get_config, set_severity_overrides, Config};
 use load_folder_structure::{count_files, load_folder_structure};
 use parse_config_file::parse_config_file;
 use test_config::test_config;
@@ -99,6 +100,8 @@ fn main() {
             root,
             cfg_path,
             allow_warnings,
+            severity_overrides,
+            max_warnings,
         } => {
             let parsed_config = match parse_config_file(&cfg_path) {
                 Ok(config) => config,
@@ -112,7 +115,10 @@ fn main() {
                 }
             };
 
-            let config = match get_config(&parsed_config) {
+            let config = match get_config(&parsed_config).and_then(|mut config| {
+                set_severity_overrides(&mut config, &severity_overrides)?;
+                Ok(config)
+            }) {
                 Ok(config) => config,
                 Err(err) => {
                     eprintln!("❌ Error building config: {}", err);
@@ -120,12 +126,17 @@ fn main() {
                 }
             };
 
-            lint(config, root, allow_warnings);
+            lint(config, root, allow_warnings, max_warnings);
         }
     }
 }
 
-fn lint(config: Config, root: PathBuf, allow_warnings: bool) {
+fn lint(
+    config: Config,
+    root: PathBuf,
+    allow_warnings: bool,
+    max_warnings: Option<usize>,
+) {
     let measure_time = std::time::Instant::now();
 
     let root_structure = match load_folder_structure(&root, &config, &root, true) {
@@ -143,9 +154,16 @@ fn lint(config: Config, root: PathBuf, allow_warnings: bool) {
         std::process::exit(1);
     };
 
-    if let Err(Problems { errors, warnings }) =
-        check_root_folder(&config, &root_structure, false, allow_warnings)
-    {
+    if let Err(Problems {
+        errors,
+        warnings,
+        infos,
+    }) = check_root_folder(
+        &config,
+        &root_structure,
+        false,
+        allow_warnings || max_warnings.is_some(),
+    ) {
         let mut should_exit = false;
 
         if !errors.is_empty() {
@@ -163,6 +181,24 @@ fn lint(config: Config, root: PathBuf, allow_warnings: bool) {
             );
         }
 
+        if !infos.is_empty() {
+            eprintln!(
+                "🔵 Infos found in the project:\n\n{}\n\n",
+                infos.join("\n\n")
+            );
+        }
+
+        if let Some(max_warnings) = max_warnings {
+            if warnings.len() > max_warnings {
+                should_exit = true;
+                eprintln!(
+                    "❌ Found {} warnings, the maximum allowed is {}",
+                    warnings.len(),
+                    max_warnings
+                );
+            }
+        }
+
         if should_exit {
             std::process::exit(1);
         }
@@ -16,17 +16,35 @@ pub enum SingleOrMultiple<T> {
 #[derive(Deserialize, Debug, Clone, Default)]
 pub struct ParsedFileConditions {
     pub has_extension: Option<SingleOrMultiple<String>>,
+    pub has_suffix: Option<SingleOrMultiple<String>>,
     pub has_name: Option<String>,
     pub not_has_name: Option<String>,
+    pub has_path: Option<String>,
+    pub not_has_path: Option<String>,
+    pub in_folder: Option<String>,
+    pub not_in_folder: Option<String>,
     pub is_ts: Option<bool>,
+    pub is_symlink: Option<bool>,
+    pub is_larger_than: Option<ParsedFileSize>,
+    pub is_binary: Option<bool>,
     pub has_content: Option<ParsedFileContentMatches>,
     pub has_any_content: Option<ParsedFileContentMatches>,
-    pub not_has_content: Option<SingleOrMultiple<String>>,
+    pub not_has_content: Option<SingleOrMultiple<ParsedContentNotMatchesItem>>,
+    pub all_of: Option<Vec<ParsedFileConditions>>,
+    pub any_of: Option<Vec<ParsedFileConditions>>,
+    pub not: Option<Box<ParsedFileConditions>>,
 
     #[serde(flatten)]
     pub wrong: HashMap<String, Value>,
 }
 
+#[derive(Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum ParsedFileSize {
+    Bytes(u64),
+    WithUnit(String),
+}
+
 #[derive(Deserialize, Debug, Clone)]
 pub struct ParsedMatchImport {
     pub from: String,
@@ -45,20 +63,62 @@ pub struct ParsedTsFileExpect {
     pub not_have_imports: Option<Vec<ParsedMatchImport>>,
 }
 
+#[derive(Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum ParsedUniqueNameScope {
+    Scope(String),
+    Config {
+        scope: String,
+        ignore: Option<Vec<String>>,
+    },
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum ParsedSiblingPattern {
+    Pattern(String),
+    Config {
+        pattern: String,
+        at_least: Option<usize>,
+        at_most: Option<usize>,
+    },
+}
+
 #[derive(Deserialize, Debug, Clone)]
 pub struct ParsedFileExpect {
-    pub name_case_is: Option<String>,
+    pub name_case_is: Option<SingleOrMultiple<String>>,
     pub extension_is: Option<SingleOrMultiple<String>>,
-    pub have_sibling_file: Option<String>,
+    pub suffix_is: Option<SingleOrMultiple<String>>,
+    pub have_sibling_file: Option<ParsedSiblingPattern>,
+    pub have_sibling_folder: Option<ParsedSiblingPattern>,
+    pub not_have_sibling_file: Option<String>,
+    pub not_have_sibling_folder: Option<String>,
+    pub have_companion_file: Option<String>,
     pub content_matches: Option<ParsedFileContentMatches>,
     pub content_matches_any: Option<ParsedFileContentMatches>,
-    pub content_not_matches: Option<SingleOrMultiple<String>>,
+    pub content_not_matches: Option<SingleOrMultiple<ParsedContentNotMatchesItem>>,
     pub name_is: Option<String>,
     pub name_is_not: Option<String>,
+    pub name_is_unique_in: Option<ParsedUniqueNameScope>,
     pub is_not_empty: Option<bool>,
+    pub max_size: Option<ParsedFileSize>,
+    pub max_lines: Option<usize>,
+    pub min_lines: Option<usize>,
+    pub encoding_is: Option<String>,
+    pub content_is_unique: Option<bool>,
+    pub json_matches: Option<SingleOrMultiple<ParsedDataMatches>>,
+    pub yaml_matches: Option<SingleOrMultiple<ParsedDataMatches>>,
+    pub json_schema: Option<ParsedJsonSchema>,
+    pub front_matter_has: Option<SingleOrMultiple<String>>,
+    pub has_heading: Option<SingleOrMultiple<ParsedHeadingPattern>>,
+    pub relative_links_resolve: Option<bool>,
     pub ts: Option<ParsedTsFileExpect>,
+    pub all_of: Option<Vec<ParsedFileExpect>>,
+    pub any_of: Option<Vec<ParsedFileExpect>>,
+    pub not: Option<Box<ParsedFileExpect>>,
 
     pub error_msg: Option<String>,
+    pub severity: Option<String>,
 
     #[serde(flatten)]
     pub wrong: HashMap<String, Value>,
@@ -66,10 +126,21 @@ pub struct ParsedFileExpect {
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct ParsedFolderConditions {
-    pub has_name_case: Option<String>,
+    pub has_name_case: Option<SingleOrMultiple<String>>,
     pub has_name: Option<String>,
     pub not_has_name: Option<String>,
+    pub has_path: Option<String>,
+    pub not_has_path: Option<String>,
+    pub in_folder: Option<String>,
+    pub not_in_folder: Option<String>,
     pub root_files_find_pattern: Option<ParsedFindPattern>,
+    pub is_symlink: Option<bool>,
+    pub depth_is: Option<usize>,
+    pub depth_at_least: Option<usize>,
+    pub depth_from: Option<String>,
+    pub all_of: Option<Vec<ParsedFolderConditions>>,
+    pub any_of: Option<Vec<ParsedFolderConditions>>,
+    pub not: Option<Box<ParsedFolderConditions>>,
 
     #[serde(flatten)]
     pub wrong: HashMap<String, Value>,
@@ -77,26 +148,68 @@ pub struct ParsedFolderConditions {
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct ParsedFolderExpect {
-    pub name_case_is: Option<String>,
+    pub name_case_is: Option<SingleOrMultiple<String>>,
     pub name_is: Option<String>,
     pub name_is_not: Option<String>,
+    pub name_is_unique_in: Option<ParsedUniqueNameScope>,
     pub root_files_has: Option<String>,
     pub root_files_has_not: Option<String>,
     pub have_min_children: Option<usize>,
+    pub is_not_empty: Option<ParsedEmptyFolderCheck>,
+    pub max_children: Option<usize>,
+    pub max_files: Option<usize>,
+    pub max_folders: Option<usize>,
+    pub max_depth: Option<usize>,
     pub child_rules: Option<Vec<ParsedRule>>,
+    pub all_of: Option<Vec<ParsedFolderExpect>>,
+    pub any_of: Option<Vec<ParsedFolderExpect>>,
+    pub not: Option<Box<ParsedFolderExpect>>,
 
     pub error_msg: Option<String>,
+    pub severity: Option<String>,
 
     #[serde(flatten)]
     pub wrong: HashMap<String, Value>,
 }
 
+#[derive(Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum ParsedEmptyFolderCheck {
+    Enabled(bool),
+    Config { ignore: Vec<String> },
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum ParsedDuplicateContent {
+    Enabled(bool),
+    Config {
+        min_size: Option<ParsedFileSize>,
+        scopes: Option<Vec<String>>,
+    },
+}
+
 #[derive(Deserialize, Debug, Clone)]
 pub struct ParsedFileContentMatchesConfig {
     pub all: Option<Vec<String>>,
     pub any: Option<Vec<String>>,
     pub at_least: Option<usize>,
     pub at_most: Option<usize>,
+    pub in_first_lines: Option<usize>,
+    pub on_line: Option<usize>,
+    pub in_range: Option<(usize, usize)>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum ParsedContentNotMatchesItem {
+    Pattern(String),
+    Config {
+        pattern: String,
+        in_first_lines: Option<usize>,
+        on_line: Option<usize>,
+        in_range: Option<(usize, usize)>,
+    },
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -117,6 +230,35 @@ pub enum ParsedFileContentMatches {
     Error(Value),
 }
 
+#[derive(Deserialize, Debug, Clone)]
+pub struct ParsedDataMatches {
+    pub path: String,
+    pub equals: Option<serde_json::Value>,
+    pub matches: Option<String>,
+    pub exists: Option<bool>,
+    #[serde(rename = "type")]
+    pub type_is: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum ParsedJsonSchema {
+    Path(String),
+    Inline(serde_json::Value),
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum ParsedHeadingPattern {
+    Text(String),
+    Config {
+        level: Option<u8>,
+        text: Option<String>,
+        at_least: Option<usize>,
+        at_most: Option<usize>,
+    },
+}
+
 #[derive(Deserialize, Debug, Clone)]
 pub struct ParsedFindPattern {
     pub pattern: String,
@@ -146,6 +288,8 @@ pub enum ParsedRule {
         not_touch: Option<bool>,
         ignore_in_config_tests: Option<bool>,
         is_warning: Option<bool>,
+        id: Option<String>,
+        severity: Option<String>,
     },
     Folder {
         #[serde(rename = "if_folder")]
@@ -161,6 +305,8 @@ pub enum ParsedRule {
         non_recursive: Option<bool>,
         not_touch: Option<bool>,
         is_warning: Option<bool>,
+        id: Option<String>,
+        severity: Option<String>,
     },
     OneOf {
         #[serde(rename = "one_of")]
@@ -184,6 +330,7 @@ pub struct CorrectParsedFolderConfig {
     pub unexpected_folders_error_msg: Option<String>,
     pub unexpected_error_msg: Option<String>,
     pub append_error_msg: Option<String>,
+    pub case_collisions: Option<bool>,
 
     #[serde(flatten)]
     pub folders: BTreeMap<String, ParsedFolderConfig>,
@@ -209,8 +356,17 @@ pub struct ParsedConfig {
     pub blocks: ParsedBlocks,
     pub allow_warnings: Option<bool>,
     pub error_msg_vars: Option<BTreeMap<String, String>>,
+    pub name_cases: Option<BTreeMap<String, String>>,
+    pub acronyms: Option<Vec<String>>,
+    pub strict_acronyms: Option<bool>,
     pub analyze_content_of_files_types: Option<Vec<String>>,
     pub ignore: Option<Vec<String>>,
+    pub use_gitignore: Option<bool>,
+    pub dotfiles: Option<String>,
+    pub symlinks: Option<String>,
+    pub encoding: Option<String>,
+    pub report_empty_folders: Option<ParsedEmptyFolderCheck>,
+    pub duplicate_content: Option<ParsedDuplicateContent>,
     pub ts: Option<ParsedTsConfig>,
 
     #[serde(rename = "./")]
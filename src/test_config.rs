@@ -10,11 +10,12 @@ use crate::{
     analyze_ts_deps::{_setup_test, load_used_project_files_deps_info_from_cfg},
     check_folders::{check_root_folder, normalize_folder_config_name, Problems},
     internal_config::get_config,
-    load_folder_structure::{File, Folder, FolderChild},
+    load_folder_structure::{count_lines, File, Folder, FolderChild},
     parse_config_file,
     test_utils::TEST_MUTEX,
 };
 use serde::{Deserialize, Serialize};
+use xxhash_rust::xxh3::xxh3_64;
 
 #[derive(Debug, Clone)]
 struct TestCase {
@@ -430,9 +431,19 @@ fn convert_from_parsed_folder_to_project(
                 Ok(FolderChild::FileChild(File {
                     basename,
                     name_with_ext: child_string.clone(),
+                    size: content_to_use.len() as u64,
+                    line_count: count_lines(&content_to_use),
+                    is_binary: content_to_use.contains('\0'),
+                    is_utf8: true,
+                    content_hash: (!content_to_use.is_empty())
+                        .then(|| xxh3_64(content_to_use.as_bytes())),
                     content: Some(content_to_use),
+                    content_path: None,
+                    encoding: None,
+                    path: None,
                     extension: Some(extension),
                     relative_path: format!("{}/{}", path, child_string),
+                    is_symlink: false,
                 }))
                 }
                 ParsedStructureChild::Folder(folder) => {
@@ -451,6 +462,7 @@ fn convert_from_parsed_folder_to_project(
     Ok(Folder {
         name: normalize_folder_config_name(&folder_name),
         children: childs,
+        is_symlink: false,
     })
 }
 
@@ -591,7 +603,7 @@ mod tests {
         assert_debug_snapshot!(stripped_result,
             @r###"
         Err(
-            "\n\n❌ Test case 'test.md' - project 3: Expected Ok but got errors: Problems {\n    errors: [\n        \"File ./stores/test_examples.ts:\\n • should be named in camelCase\",\n    ],\n    warnings: [],\n}\n\n\n🟩 Running 1 test cases\n\n",
+            "\n\n❌ Test case 'test.md' - project 3: Expected Ok but got errors: Problems {\n    errors: [\n        \"File ./stores/test_examples.ts:\\n • should be named in camelCase\",\n    ],\n    warnings: [],\n    infos: [],\n}\n\n\n🟩 Running 1 test cases\n\n",
         )
         "###
         )
@@ -656,16 +668,29 @@ mod tests {
                                         content: Some(
                                             "import { test } from 'test';\n\ntest();\n",
                                         ),
+                                        content_path: None,
+                                        encoding: None,
+                                        path: None,
                                         extension: Some(
                                             "ts",
                                         ),
                                         relative_path: "./src/test.ts",
+                                        is_symlink: false,
+                                        size: 38,
+                                        line_count: 3,
+                                        is_binary: false,
+                                        is_utf8: true,
+                                        content_hash: Some(
+                                            10992315487343444045,
+                                        ),
                                     },
                                 ),
                             ],
+                            is_symlink: false,
                         },
                     ),
                 ],
+                is_symlink: false,
             },
             expected_errors: None,
         }